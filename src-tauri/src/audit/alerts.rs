@@ -0,0 +1,227 @@
+//! Evaluates appended audit entries against configurable alert rules —
+//! "≥3 Security events in 5 minutes", "any Critical entry" — and fires
+//! a native notification plus an optional webhook call when one trips.
+//! Triggered alerts are kept in memory for `list_triggered_alerts_command`;
+//! nothing here persists across a restart, since an alert is a
+//! real-time signal rather than a durable record — the audit log
+//! itself is still the durable record of what happened.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::entry::{LogEntry, LogLevel};
+
+/// Bounds the in-memory triggered-alert history so a noisy rule can't
+/// grow this unbounded — the same reasoning as the shipper's spool,
+/// just capped by count instead of never needing to persist at all.
+const MAX_TRIGGERED_HISTORY: usize = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertCondition {
+    /// Fires once `count` entries at `level` have landed within the
+    /// trailing `window_secs` seconds.
+    ThresholdInWindow { level: LogLevel, count: u32, window_secs: u64 },
+    /// Fires on every entry at `level`.
+    AnyAtLevel { level: LogLevel },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub id: Uuid,
+    pub name: String,
+    pub condition: AlertCondition,
+    pub enabled: bool,
+    /// Posted the triggered alert as JSON when set, best-effort and
+    /// unlike `audit::shipper`, which spools failures for retry — a
+    /// failing webhook is logged, not retried, since an alert that's moments late is still useful but
+    /// a blocked write path isn't worth the retry machinery here.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TriggeredAlert {
+    pub rule_id: Uuid,
+    pub rule_name: String,
+    pub triggered_at: DateTime<Utc>,
+    pub entry: LogEntry,
+}
+
+/// Requires the `tauri-plugin-notification` plugin to be registered for
+/// native notifications to actually show; without it, `fire` still
+/// records the triggered alert and attempts the webhook.
+pub struct AlertEngine {
+    rules: Mutex<Vec<AlertRule>>,
+    recent_by_level: Mutex<HashMap<String, VecDeque<DateTime<Utc>>>>,
+    triggered: Mutex<VecDeque<TriggeredAlert>>,
+    client: reqwest::Client,
+    app_handle: Option<AppHandle>,
+}
+
+impl AlertEngine {
+    pub fn new(rules: Vec<AlertRule>, app_handle: Option<AppHandle>) -> Self {
+        Self {
+            rules: Mutex::new(rules),
+            recent_by_level: Mutex::new(HashMap::new()),
+            triggered: Mutex::new(VecDeque::new()),
+            client: reqwest::Client::new(),
+            app_handle,
+        }
+    }
+
+    pub fn rules(&self) -> Vec<AlertRule> {
+        self.rules.lock().unwrap().clone()
+    }
+
+    pub fn set_rules(&self, rules: Vec<AlertRule>) {
+        *self.rules.lock().unwrap() = rules;
+    }
+
+    pub fn triggered_alerts(&self) -> Vec<TriggeredAlert> {
+        self.triggered.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Evaluates every enabled rule against `entry`, firing whichever
+    /// trip. Called from `LogWriter::append` for every entry, the same
+    /// way the index and shipper hook in.
+    pub fn check(&self, entry: &LogEntry) {
+        self.recent_by_level
+            .lock()
+            .unwrap()
+            .entry(entry.level.as_str().to_string())
+            .or_default()
+            .push_back(entry.timestamp);
+
+        for rule in self.rules().into_iter().filter(|rule| rule.enabled) {
+            if self.evaluate(&rule.condition, entry) {
+                self.fire(&rule, entry);
+            }
+        }
+    }
+
+    fn evaluate(&self, condition: &AlertCondition, entry: &LogEntry) -> bool {
+        match condition {
+            AlertCondition::AnyAtLevel { level } => entry.level == *level,
+            AlertCondition::ThresholdInWindow { level, count, window_secs } => {
+                if entry.level != *level {
+                    return false;
+                }
+                let cutoff = entry.timestamp - chrono::Duration::seconds(*window_secs as i64);
+                let mut recent_by_level = self.recent_by_level.lock().unwrap();
+                let Some(timestamps) = recent_by_level.get_mut(level.as_str()) else { return false };
+                while timestamps.front().is_some_and(|ts| *ts < cutoff) {
+                    timestamps.pop_front();
+                }
+                timestamps.len() as u32 >= *count
+            }
+        }
+    }
+
+    fn fire(&self, rule: &AlertRule, entry: &LogEntry) {
+        let triggered =
+            TriggeredAlert { rule_id: rule.id, rule_name: rule.name.clone(), triggered_at: Utc::now(), entry: entry.clone() };
+
+        {
+            let mut history = self.triggered.lock().unwrap();
+            history.push_front(triggered.clone());
+            history.truncate(MAX_TRIGGERED_HISTORY);
+        }
+
+        if let Some(ref app_handle) = self.app_handle {
+            if let Err(err) =
+                app_handle.notification().builder().title(format!("Audit alert: {}", rule.name)).body(&entry.message).show()
+            {
+                warn!(%err, "failed to show native audit alert notification");
+            }
+        }
+
+        if let Some(url) = rule.webhook_url.clone() {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(err) = client.post(&url).json(&triggered).send().await {
+                    warn!(%url, %err, "failed to deliver audit alert webhook");
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry_at(level: LogLevel, timestamp: DateTime<Utc>) -> LogEntry {
+        LogEntry {
+            timestamp,
+            level,
+            action: "test.action".to_string(),
+            user: None,
+            message: "something happened".to_string(),
+            metadata: serde_json::Value::Null,
+            session_id: None,
+            request_id: None,
+            prev_hash: String::new(),
+            entry_hash: String::new(),
+        }
+    }
+
+    fn rule(condition: AlertCondition) -> AlertRule {
+        AlertRule { id: Uuid::new_v4(), name: "test rule".to_string(), condition, enabled: true, webhook_url: None }
+    }
+
+    #[test]
+    fn any_at_level_fires_on_the_first_matching_entry() {
+        let engine = AlertEngine::new(vec![rule(AlertCondition::AnyAtLevel { level: LogLevel::Critical })], None);
+        engine.check(&entry_at(LogLevel::Critical, Utc::now()));
+        assert_eq!(engine.triggered_alerts().len(), 1);
+    }
+
+    #[test]
+    fn any_at_level_does_not_fire_for_other_levels() {
+        let engine = AlertEngine::new(vec![rule(AlertCondition::AnyAtLevel { level: LogLevel::Critical })], None);
+        engine.check(&entry_at(LogLevel::Info, Utc::now()));
+        assert!(engine.triggered_alerts().is_empty());
+    }
+
+    #[test]
+    fn a_disabled_rule_never_fires() {
+        let mut disabled = rule(AlertCondition::AnyAtLevel { level: LogLevel::Critical });
+        disabled.enabled = false;
+        let engine = AlertEngine::new(vec![disabled], None);
+        engine.check(&entry_at(LogLevel::Critical, Utc::now()));
+        assert!(engine.triggered_alerts().is_empty());
+    }
+
+    #[test]
+    fn threshold_in_window_waits_for_the_count_to_be_reached() {
+        let engine = AlertEngine::new(
+            vec![rule(AlertCondition::ThresholdInWindow { level: LogLevel::Security, count: 2, window_secs: 60 })],
+            None,
+        );
+        let now = Utc::now();
+        engine.check(&entry_at(LogLevel::Security, now));
+        assert!(engine.triggered_alerts().is_empty());
+        engine.check(&entry_at(LogLevel::Security, now));
+        assert_eq!(engine.triggered_alerts().len(), 1);
+    }
+
+    #[test]
+    fn threshold_in_window_ignores_entries_outside_the_window() {
+        let engine = AlertEngine::new(
+            vec![rule(AlertCondition::ThresholdInWindow { level: LogLevel::Security, count: 2, window_secs: 60 })],
+            None,
+        );
+        let old = Utc::now() - chrono::Duration::seconds(120);
+        engine.check(&entry_at(LogLevel::Security, old));
+        engine.check(&entry_at(LogLevel::Security, Utc::now()));
+        assert!(engine.triggered_alerts().is_empty());
+    }
+}
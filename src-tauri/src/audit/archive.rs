@@ -0,0 +1,130 @@
+//! Encrypted, compressed archives of a slice of audit history for
+//! compliance hand-off or cold storage. Unlike `export`, the result
+//! isn't meant to stay readable on this machine — it's meant to leave
+//! it, so it's encrypted with a passphrase rather than written in the
+//! clear.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::Argon2;
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::search::{search_logs, LogSearchFilter};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const ZSTD_LEVEL: i32 = 19;
+const MANIFEST_SUFFIX: &str = ".manifest.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ArchiveError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    #[error("archive is corrupt or too short to contain a salt and nonce")]
+    Truncated,
+    #[error("encryption or decryption failed — wrong passphrase or tampered archive")]
+    Crypto,
+    #[error("key derivation failed")]
+    Kdf,
+}
+
+/// Written alongside the archive so a recipient can confirm nothing was
+/// altered after decrypting, without needing this machine's ed25519
+/// signing key the way `signing::verify_file` does.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub entry_count: usize,
+    /// SHA-256 of the NDJSON plaintext, taken before compression and
+    /// encryption.
+    pub plaintext_sha256: String,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArchiveSummary {
+    pub entry_count: usize,
+    pub archive_size_bytes: u64,
+}
+
+/// Archives every entry in `log_files` matching `filter` into one
+/// AES-256-GCM-encrypted, zstd-compressed file at `archive_path`, keyed
+/// by `passphrase` via Argon2id with a random salt — the same scheme
+/// `security::vault` uses for its own at-rest encryption. A manifest
+/// sidecar (`archive_path` + `.manifest.json`) records the plaintext
+/// hash and the requested range.
+pub fn archive_logs(
+    log_files: &[impl AsRef<Path>],
+    filter: &LogSearchFilter,
+    passphrase: &str,
+    archive_path: &Path,
+) -> Result<ArchiveSummary, ArchiveError> {
+    let entries = search_logs(log_files, filter);
+    let mut plaintext = Vec::new();
+    for entry in &entries {
+        plaintext.extend_from_slice(serde_json::to_string(entry)?.as_bytes());
+        plaintext.push(b'\n');
+    }
+    let plaintext_sha256 = hex::encode(Sha256::digest(&plaintext));
+    let compressed = zstd::encode_all(plaintext.as_slice(), ZSTD_LEVEL)?;
+
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| ArchiveError::Kdf)?;
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher.encrypt(nonce, compressed.as_ref()).map_err(|_| ArchiveError::Crypto)?;
+
+    let mut out = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&ciphertext);
+    fs::write(archive_path, &out)?;
+
+    let manifest = ArchiveManifest { entry_count: entries.len(), plaintext_sha256, since: filter.since, until: filter.until };
+    fs::write(manifest_path(archive_path), serde_json::to_string_pretty(&manifest)?)?;
+
+    Ok(ArchiveSummary { entry_count: entries.len(), archive_size_bytes: out.len() as u64 })
+}
+
+/// Decrypts and decompresses an archive produced by `archive_logs` back
+/// into NDJSON bytes. Doesn't consult the manifest itself — a caller
+/// that wants the integrity check compares `Sha256::digest` of the
+/// result against the manifest's `plaintext_sha256`.
+pub fn open_archive(archive_path: &Path, passphrase: &str) -> Result<Vec<u8>, ArchiveError> {
+    let raw = fs::read(archive_path)?;
+    if raw.len() < SALT_LEN + NONCE_LEN {
+        return Err(ArchiveError::Truncated);
+    }
+    let (salt, rest) = raw.split_at(SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let key = derive_key(passphrase, salt)?;
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| ArchiveError::Kdf)?;
+    let nonce = Nonce::from_slice(nonce_bytes);
+    let compressed = cipher.decrypt(nonce, ciphertext).map_err(|_| ArchiveError::Crypto)?;
+
+    Ok(zstd::decode_all(compressed.as_slice())?)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], ArchiveError> {
+    let mut key = [0u8; 32];
+    Argon2::default().hash_password_into(passphrase.as_bytes(), salt, &mut key).map_err(|_| ArchiveError::Kdf)?;
+    Ok(key)
+}
+
+fn manifest_path(archive_path: &Path) -> PathBuf {
+    let mut name = archive_path.as_os_str().to_os_string();
+    name.push(MANIFEST_SUFFIX);
+    PathBuf::from(name)
+}
@@ -0,0 +1,76 @@
+//! Compresses rotated log files with zstd once they're no longer being
+//! appended to. A small JSON sidecar records the pre-compression size
+//! so statistics can report savings without decompressing just to
+//! measure — the same sidecar-file pattern `signing` uses for `.sig`.
+
+use std::fs;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+pub const COMPRESSED_SUFFIX: &str = ".zst";
+const META_SUFFIX: &str = ".meta.json";
+const ZSTD_LEVEL: i32 = 19;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompressionError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct CompressionMeta {
+    original_size_bytes: u64,
+}
+
+/// Compresses `log_path` in place: writes `log_path` + `.zst`, a sizing
+/// sidecar next to it, then removes the original. Returns the path to
+/// the compressed file.
+pub fn compress_log_file(log_path: &Path) -> Result<PathBuf, CompressionError> {
+    let original_size_bytes = fs::metadata(log_path)?.len();
+    let raw = fs::read(log_path)?;
+    let compressed = zstd::encode_all(raw.as_slice(), ZSTD_LEVEL)?;
+
+    let compressed_path = with_suffix(log_path, COMPRESSED_SUFFIX);
+    fs::write(&compressed_path, compressed)?;
+    fs::write(meta_path(&compressed_path), serde_json::to_string(&CompressionMeta { original_size_bytes })?)?;
+    fs::remove_file(log_path)?;
+
+    Ok(compressed_path)
+}
+
+/// The pre-compression size of a rotated file, if it was compressed
+/// through `compress_log_file` (and its sidecar hasn't been deleted).
+pub fn original_size_bytes(compressed_path: &Path) -> Option<u64> {
+    let raw = fs::read_to_string(meta_path(compressed_path)).ok()?;
+    serde_json::from_str::<CompressionMeta>(&raw).ok().map(|meta| meta.original_size_bytes)
+}
+
+pub fn is_compressed(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "zst")
+}
+
+/// Opens `path` for line-oriented reading, transparently decompressing
+/// it first if it's a `.zst` file — so `search`/`export` don't need to
+/// know which rotated files happen to be compressed.
+pub fn open_reader(path: &Path) -> std::io::Result<Box<dyn std::io::BufRead>> {
+    let file = fs::File::open(path)?;
+    if is_compressed(path) {
+        Ok(Box::new(BufReader::new(zstd::stream::Decoder::new(file)?)))
+    } else {
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+fn meta_path(compressed_path: &Path) -> PathBuf {
+    with_suffix(compressed_path, META_SUFFIX)
+}
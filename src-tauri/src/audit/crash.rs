@@ -0,0 +1,173 @@
+//! Panic and unclean-shutdown capture. A panic can happen before (or
+//! while) anything that depends on a `LogWriter` or the async runtime
+//! is usable, so the panic hook itself only ever does synchronous,
+//! minimal file I/O straight into the log directory — turning a
+//! captured panic into a proper Critical audit entry happens on the
+//! *next* launch, once a `LogWriter` exists to write one through.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::event::log_critical;
+use super::writer::LogWriter;
+use crate::security::events::RequestContext;
+
+const RUNNING_MARKER_FILE_NAME: &str = "running.marker";
+const CRASH_REPORT_PREFIX: &str = "crash-";
+const CRASH_REPORT_SUFFIX: &str = ".json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrashKind {
+    Panic,
+    UncleanShutdown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub occurred_at: DateTime<Utc>,
+    pub kind: CrashKind,
+    pub message: String,
+    pub backtrace: Option<String>,
+}
+
+/// Last set of crash reports recovered at startup, held for
+/// `get_crash_reports_command` — the report files themselves are
+/// deleted as they're read, so something has to hold onto them for the
+/// rest of the session.
+pub struct CrashReports(std::sync::Mutex<Vec<CrashReport>>);
+
+impl CrashReports {
+    pub fn new(reports: Vec<CrashReport>) -> Self {
+        Self(std::sync::Mutex::new(reports))
+    }
+
+    pub fn list(&self) -> Vec<CrashReport> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+/// Installs the panic hook, chaining to whatever hook was previously
+/// installed (the default one prints to stderr) after persisting a
+/// crash report — panics still show up in the terminal exactly as
+/// before this was added.
+pub fn install_panic_hook(log_dir: PathBuf) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = CrashReport {
+            occurred_at: Utc::now(),
+            kind: CrashKind::Panic,
+            message: panic_message(info),
+            backtrace: Some(std::backtrace::Backtrace::force_capture().to_string()),
+        };
+        if let Err(err) = write_crash_report(&log_dir, &report) {
+            eprintln!("failed to persist crash report: {err}");
+        }
+        previous(info);
+    }));
+}
+
+fn panic_message(info: &std::panic::PanicHookInfo<'_>) -> String {
+    let payload = if let Some(s) = info.payload().downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = info.payload().downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "panic with non-string payload".to_string()
+    };
+    match info.location() {
+        Some(location) => format!("{payload} ({}:{})", location.file(), location.line()),
+        None => payload,
+    }
+}
+
+fn write_crash_report(log_dir: &Path, report: &CrashReport) -> std::io::Result<()> {
+    fs::create_dir_all(log_dir)?;
+    let raw = serde_json::to_string(report).unwrap_or_else(|_| "{}".to_string());
+    let path = log_dir.join(format!("{CRASH_REPORT_PREFIX}{}-{}{CRASH_REPORT_SUFFIX}", report.occurred_at.format("%Y%m%dT%H%M%S%.9f"), std::process::id()));
+    fs::write(path, raw)
+}
+
+fn running_marker_path(log_dir: &Path) -> PathBuf {
+    log_dir.join(RUNNING_MARKER_FILE_NAME)
+}
+
+/// Leaves a marker in `log_dir` for the duration of this run. Call
+/// `clear_running_marker` on clean shutdown — if that never happens,
+/// the next launch's `recover_crash_reports` finds the marker still
+/// there and knows this run didn't exit cleanly.
+pub fn mark_running(log_dir: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(log_dir)?;
+    fs::write(running_marker_path(log_dir), Utc::now().to_rfc3339())
+}
+
+pub fn clear_running_marker(log_dir: &Path) {
+    let _ = fs::remove_file(running_marker_path(log_dir));
+}
+
+/// Collects any crash-report files the panic hook left behind, deletes
+/// them (they're about to become audit entries, so nothing else should
+/// read them again), and checks whether the running marker survived
+/// from the previous launch.
+fn collect_pending_reports(log_dir: &Path) -> Vec<CrashReport> {
+    let mut reports = Vec::new();
+    if let Ok(read_dir) = fs::read_dir(log_dir) {
+        for entry in read_dir.filter_map(Result::ok) {
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            if !file_name.starts_with(CRASH_REPORT_PREFIX) || !file_name.ends_with(CRASH_REPORT_SUFFIX) {
+                continue;
+            }
+            let path = entry.path();
+            if let Ok(raw) = fs::read_to_string(&path) {
+                if let Ok(report) = serde_json::from_str(&raw) {
+                    reports.push(report);
+                }
+            }
+            let _ = fs::remove_file(&path);
+        }
+    }
+
+    let marker_path = running_marker_path(log_dir);
+    if marker_path.exists() {
+        reports.push(CrashReport {
+            occurred_at: Utc::now(),
+            kind: CrashKind::UncleanShutdown,
+            message: "previous run's running marker was still present at startup".to_string(),
+            backtrace: None,
+        });
+        let _ = fs::remove_file(&marker_path);
+    }
+
+    reports.sort_by_key(|report| report.occurred_at);
+    reports
+}
+
+/// Call once at startup, after the `LogWriter` is ready and before
+/// `mark_running` — turns panics from the previous run (and an unclean
+/// shutdown, if the marker is still there) into Critical audit entries,
+/// and returns them for `get_crash_reports_command` to serve for the
+/// rest of the session.
+pub fn recover_crash_reports(writer: &LogWriter, log_dir: &Path) -> Vec<CrashReport> {
+    let reports = collect_pending_reports(log_dir);
+    let ctx = RequestContext::system();
+
+    for report in &reports {
+        let action = match report.kind {
+            CrashKind::Panic => "crash.panic",
+            CrashKind::UncleanShutdown => "crash.unclean_shutdown",
+        };
+        let message = match &report.backtrace {
+            Some(backtrace) => format!("{}\n{backtrace}", report.message),
+            None => report.message.clone(),
+        };
+        if let Err(err) = log_critical(writer, &ctx, action, message) {
+            warn!(%err, "failed to record crash report in audit log");
+        }
+    }
+
+    reports
+}
@@ -0,0 +1,114 @@
+//! Optional encryption at rest for audit log lines, for operators whose
+//! logs capture sensitive transcripts. Off by default; once enabled via
+//! `LogWriter::with_encryption`, every entry written afterward is
+//! sealed with AES-256-GCM under a key held in the keyring — the same
+//! `keyring_store` every other secret in this crate goes through — with
+//! a fresh nonce per line standing in for a stream cipher's running
+//! keystream, since appends are independent writes and the file is
+//! never rewritten in place.
+//!
+//! Decryption is line-oblivious rather than file-oblivious: a line
+//! starting with `ENC_PREFIX` is decrypted, anything else (a plaintext
+//! line written before encryption was turned on) passes through
+//! unchanged. That keeps search and export transparent across a file
+//! that mixes cleartext and encrypted entries, without either caller
+//! needing to know which it's looking at.
+
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use rand::RngCore;
+use tracing::warn;
+
+use crate::security::keyring_store::{self, KeyringError};
+
+const ENCRYPTION_KEY_SECRET: &str = "audit_log_encryption_key";
+const NONCE_LEN: usize = 12;
+pub const ENC_PREFIX: &str = "ENC1:";
+
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    #[error(transparent)]
+    Keyring(#[from] KeyringError),
+    #[error("encryption or decryption failed")]
+    Crypto,
+    #[error("encrypted line is malformed")]
+    Malformed,
+}
+
+/// Loads the key from the keyring, generating and storing one on first
+/// use — same pattern as `signing::load_or_create_signing_key`, just a
+/// raw symmetric key instead of an ed25519 keypair.
+fn load_or_create_key() -> Result<[u8; 32], EncryptionError> {
+    if let Some(encoded) = keyring_store::get_secret(ENCRYPTION_KEY_SECRET)? {
+        let bytes = hex::decode(encoded).map_err(|_| EncryptionError::Malformed)?;
+        return bytes.try_into().map_err(|_| EncryptionError::Malformed);
+    }
+
+    let mut key = [0u8; 32];
+    OsRng.fill_bytes(&mut key);
+    keyring_store::set_secret(ENCRYPTION_KEY_SECRET, &hex::encode(key))?;
+    Ok(key)
+}
+
+/// Seals one log line, returning `ENC_PREFIX` followed by hex-encoded
+/// nonce + ciphertext.
+pub fn encrypt_line(plaintext: &str) -> Result<String, EncryptionError> {
+    let key = load_or_create_key()?;
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| EncryptionError::Crypto)?;
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher.encrypt(nonce, plaintext.as_bytes()).map_err(|_| EncryptionError::Crypto)?;
+
+    let mut combined = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    combined.extend_from_slice(&nonce_bytes);
+    combined.extend_from_slice(&ciphertext);
+    Ok(format!("{ENC_PREFIX}{}", hex::encode(combined)))
+}
+
+fn decrypt_line(line: &str) -> Result<String, EncryptionError> {
+    let key = load_or_create_key()?;
+    let combined = hex::decode(line.strip_prefix(ENC_PREFIX).ok_or(EncryptionError::Malformed)?).map_err(|_| EncryptionError::Malformed)?;
+    if combined.len() < NONCE_LEN {
+        return Err(EncryptionError::Malformed);
+    }
+    let (nonce_bytes, ciphertext) = combined.split_at(NONCE_LEN);
+
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| EncryptionError::Crypto)?;
+    let nonce = Nonce::from_slice(nonce_bytes);
+    let plaintext = cipher.decrypt(nonce, ciphertext).map_err(|_| EncryptionError::Crypto)?;
+    String::from_utf8(plaintext).map_err(|_| EncryptionError::Malformed)
+}
+
+/// Decrypts `line` if it's sealed; returns it unchanged otherwise. Used
+/// by every reader (search, export, the indexed lookup path) so none of
+/// them need to know ahead of time whether a given file — or even a
+/// given line within it — is encrypted.
+pub fn decrypt_if_sealed(line: &str) -> String {
+    if !line.starts_with(ENC_PREFIX) {
+        return line.to_string();
+    }
+    match decrypt_line(line) {
+        Ok(plaintext) => plaintext,
+        Err(err) => {
+            warn!(%err, "failed to decrypt audit log line, leaving it opaque");
+            line.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `encrypt_line`/`decrypt_line` (and anything that calls them, like
+    // `decrypt_if_sealed` on a prefixed line) round-trip through the OS
+    // keyring via `load_or_create_key`, which isn't available in a
+    // headless test environment — covered here only through the one
+    // path that returns before ever reaching it.
+
+    #[test]
+    fn a_plaintext_line_passes_through_unchanged() {
+        assert_eq!(decrypt_if_sealed("plain log line"), "plain log line");
+    }
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::security::events::RequestContext;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    /// Security-relevant but not necessarily severe — admin auth,
+    /// vault access, policy changes. Routed to `security.log` rather
+    /// than the main file, per `writer::is_security_level`.
+    Security,
+    /// Security events serious enough to warrant the longest retention
+    /// this crate configures. Routed alongside `Security`.
+    Critical,
+}
+
+impl LogLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Trace => "trace",
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+            LogLevel::Security => "security",
+            LogLevel::Critical => "critical",
+        }
+    }
+}
+
+/// Hash of an entry with no predecessor — the first line written to a
+/// fresh log file chains from this rather than from a real hash.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Utc>,
+    pub level: LogLevel,
+    pub action: String,
+    pub user: Option<String>,
+    pub message: String,
+    #[serde(default)]
+    pub metadata: serde_json::Value,
+    /// The app-run this entry was written during — see
+    /// `crate::session::AppSession`. `None` for entries from before
+    /// session correlation existed, or from a caller that never built
+    /// one through `RequestContext`.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// The single Tauri command invocation this entry was written
+    /// during, letting several entries from one call be grouped more
+    /// tightly than "same session".
+    #[serde(default)]
+    pub request_id: Option<String>,
+    /// Hash of the entry immediately before this one in the file
+    /// (`GENESIS_HASH` for the first entry), forming a chain so that
+    /// inserting, deleting, or editing any past entry is detectable.
+    #[serde(default)]
+    pub prev_hash: String,
+    /// SHA-256 of this entry's content plus `prev_hash`. Left empty
+    /// until `LogWriter::append` fills it in just before writing, since
+    /// computing it requires `prev_hash` to already be set.
+    #[serde(default)]
+    pub entry_hash: String,
+}
+
+impl LogEntry {
+    /// Builds an entry carrying `ctx`'s correlation ids, leaving the
+    /// hash-chain fields for `LogWriter::append` to fill in.
+    pub fn correlated(ctx: &RequestContext, level: LogLevel, action: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            level,
+            action: action.into(),
+            user: None,
+            message: message.into(),
+            metadata: serde_json::Value::Null,
+            session_id: ctx.session_id.clone(),
+            request_id: Some(ctx.request_id.to_string()),
+            prev_hash: String::new(),
+            entry_hash: String::new(),
+        }
+    }
+
+    /// Hashes the entry's own fields together with `prev_hash`, the
+    /// same primitive `security::modifications` uses for integrity
+    /// records. `entry_hash` itself is excluded since it's the output.
+    pub fn compute_hash(&self, prev_hash: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(prev_hash.as_bytes());
+        hasher.update(self.timestamp.to_rfc3339().as_bytes());
+        hasher.update(self.level.as_str().as_bytes());
+        hasher.update(self.action.as_bytes());
+        hasher.update(self.user.as_deref().unwrap_or("").as_bytes());
+        hasher.update(self.message.as_bytes());
+        hasher.update(self.metadata.to_string().as_bytes());
+        hasher.update(self.session_id.as_deref().unwrap_or("").as_bytes());
+        hasher.update(self.request_id.as_deref().unwrap_or("").as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_entry() -> LogEntry {
+        LogEntry {
+            timestamp: Utc::now(),
+            level: LogLevel::Info,
+            action: "test.action".to_string(),
+            user: Some("alice".to_string()),
+            message: "hello".to_string(),
+            metadata: serde_json::Value::Null,
+            session_id: None,
+            request_id: None,
+            prev_hash: String::new(),
+            entry_hash: String::new(),
+        }
+    }
+
+    #[test]
+    fn compute_hash_is_deterministic_for_the_same_inputs() {
+        let entry = sample_entry();
+        assert_eq!(entry.compute_hash(GENESIS_HASH), entry.compute_hash(GENESIS_HASH));
+    }
+
+    #[test]
+    fn compute_hash_changes_with_prev_hash() {
+        let entry = sample_entry();
+        assert_ne!(entry.compute_hash(GENESIS_HASH), entry.compute_hash("some-other-hash"));
+    }
+
+    #[test]
+    fn compute_hash_changes_if_message_is_tampered_with() {
+        let mut entry = sample_entry();
+        let original = entry.compute_hash(GENESIS_HASH);
+        entry.message = "tampered".to_string();
+        assert_ne!(original, entry.compute_hash(GENESIS_HASH));
+    }
+}
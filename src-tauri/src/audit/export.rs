@@ -0,0 +1,198 @@
+//! Writes matched log entries out to a file in whichever format the
+//! caller wants, reusing `search::search_logs` rather than duplicating
+//! the filtering logic.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow::array::{Int64Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use serde::{Deserialize, Serialize};
+
+use super::entry::LogEntry;
+use super::search::{search_logs, LogSearchFilter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Ndjson,
+    Parquet,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportSummary {
+    pub entry_count: usize,
+    pub file_size_bytes: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+    #[error(transparent)]
+    Arrow(#[from] arrow::error::ArrowError),
+    #[error(transparent)]
+    Parquet(#[from] parquet::errors::ParquetError),
+}
+
+/// CSV columns are flat, but `LogEntry::metadata` is an arbitrary JSON
+/// value, so it's serialized to a string column rather than expanded.
+#[derive(Serialize)]
+struct CsvRow<'a> {
+    timestamp: String,
+    level: &'static str,
+    action: &'a str,
+    user: &'a str,
+    message: &'a str,
+    metadata: String,
+}
+
+const PROGRESS_INTERVAL: usize = 1000;
+
+/// Exports entries matching `filter` to `export_path` in `format`.
+/// `on_progress` is called with the running count so a frontend can
+/// show a progress bar on large exports: NDJSON and CSV report after
+/// every `PROGRESS_INTERVAL` entries and once more at the end, Parquet
+/// after each batch of up to `PROGRESS_INTERVAL` rows, and JSON only
+/// once, at the end.
+pub fn export_logs(
+    log_files: &[impl AsRef<Path>],
+    filter: &LogSearchFilter,
+    format: ExportFormat,
+    export_path: &Path,
+    mut on_progress: impl FnMut(usize),
+) -> Result<ExportSummary, ExportError> {
+    let entries = search_logs(log_files, filter);
+
+    match format {
+        ExportFormat::Json => {
+            let file = File::create(export_path)?;
+            serde_json::to_writer_pretty(file, &entries)?;
+            on_progress(entries.len());
+        }
+        ExportFormat::Ndjson => write_ndjson(&entries, export_path, &mut on_progress)?,
+        ExportFormat::Csv => write_csv(&entries, export_path, &mut on_progress)?,
+        ExportFormat::Parquet => write_parquet(&entries, export_path, &mut on_progress)?,
+    }
+
+    let file_size_bytes = std::fs::metadata(export_path)?.len();
+    Ok(ExportSummary { entry_count: entries.len(), file_size_bytes })
+}
+
+fn write_ndjson(
+    entries: &[LogEntry],
+    export_path: &Path,
+    on_progress: &mut impl FnMut(usize),
+) -> Result<(), ExportError> {
+    let mut file = File::create(export_path)?;
+    for (i, entry) in entries.iter().enumerate() {
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        if (i + 1) % PROGRESS_INTERVAL == 0 {
+            on_progress(i + 1);
+        }
+    }
+    on_progress(entries.len());
+    Ok(())
+}
+
+fn write_csv(
+    entries: &[LogEntry],
+    export_path: &Path,
+    on_progress: &mut impl FnMut(usize),
+) -> Result<(), ExportError> {
+    let mut writer = csv::Writer::from_path(export_path)?;
+    for (i, entry) in entries.iter().enumerate() {
+        writer.serialize(CsvRow {
+            timestamp: entry.timestamp.to_rfc3339(),
+            level: entry.level.as_str(),
+            action: &entry.action,
+            user: entry.user.as_deref().unwrap_or(""),
+            message: &entry.message,
+            metadata: entry.metadata.to_string(),
+        })?;
+        if (i + 1) % PROGRESS_INTERVAL == 0 {
+            on_progress(i + 1);
+        }
+    }
+    writer.flush()?;
+    on_progress(entries.len());
+    Ok(())
+}
+
+/// `metadata` keys that come up often enough across the crate (see
+/// `memory::ingestion`'s chunk records) to earn their own typed column;
+/// everything else stays in `metadata_json` so the schema doesn't have
+/// to chase every caller's ad-hoc fields.
+fn parquet_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("timestamp", DataType::Utf8, false),
+        Field::new("level", DataType::Utf8, false),
+        Field::new("action", DataType::Utf8, false),
+        Field::new("user", DataType::Utf8, true),
+        Field::new("message", DataType::Utf8, false),
+        Field::new("session_id", DataType::Utf8, true),
+        Field::new("request_id", DataType::Utf8, true),
+        Field::new("metadata_source", DataType::Utf8, true),
+        Field::new("metadata_chunk_index", DataType::Int64, true),
+        Field::new("metadata_json", DataType::Utf8, false),
+    ])
+}
+
+/// Columnar, so entries are batched into `RecordBatch`es of
+/// `PROGRESS_INTERVAL` rows each and handed to the writer one at a time
+/// (which groups them into row groups by its own size limit), rather
+/// than building one array per column for the whole export.
+fn write_parquet(
+    entries: &[LogEntry],
+    export_path: &Path,
+    on_progress: &mut impl FnMut(usize),
+) -> Result<(), ExportError> {
+    let schema = Arc::new(parquet_schema());
+    let file = File::create(export_path)?;
+    let mut writer = ArrowWriter::try_new(file, schema.clone(), None)?;
+
+    for (i, chunk) in entries.chunks(PROGRESS_INTERVAL).enumerate() {
+        let timestamp: StringArray = chunk.iter().map(|e| Some(e.timestamp.to_rfc3339())).collect();
+        let level: StringArray = chunk.iter().map(|e| Some(e.level.as_str())).collect();
+        let action: StringArray = chunk.iter().map(|e| Some(e.action.as_str())).collect();
+        let user: StringArray = chunk.iter().map(|e| e.user.as_deref()).collect();
+        let message: StringArray = chunk.iter().map(|e| Some(e.message.as_str())).collect();
+        let session_id: StringArray = chunk.iter().map(|e| e.session_id.as_deref()).collect();
+        let request_id: StringArray = chunk.iter().map(|e| e.request_id.as_deref()).collect();
+        let metadata_source: StringArray = chunk.iter().map(|e| e.metadata.get("source").and_then(|v| v.as_str())).collect();
+        let metadata_chunk_index: Int64Array =
+            chunk.iter().map(|e| e.metadata.get("chunk_index").and_then(|v| v.as_i64())).collect();
+        let metadata_json: StringArray = chunk.iter().map(|e| Some(e.metadata.to_string())).collect();
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(timestamp),
+                Arc::new(level),
+                Arc::new(action),
+                Arc::new(user),
+                Arc::new(message),
+                Arc::new(session_id),
+                Arc::new(request_id),
+                Arc::new(metadata_source),
+                Arc::new(metadata_chunk_index),
+                Arc::new(metadata_json),
+            ],
+        )?;
+        writer.write(&batch)?;
+        on_progress((i * PROGRESS_INTERVAL) + chunk.len());
+    }
+
+    writer.close()?;
+    Ok(())
+}
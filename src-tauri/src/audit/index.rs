@@ -0,0 +1,206 @@
+//! A SQLite mirror of the append-only log, keyed on the fields actually
+//! queried (timestamp, level, action, user) plus the byte offset of the
+//! matching line in the JSONL file. The JSONL file stays the source of
+//! truth — this index only exists so `search`/`stats` can run an
+//! indexed query instead of a file scan, and can always be rebuilt from
+//! it if it's lost or goes stale.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use rusqlite::{named_params, Connection};
+
+use super::entry::{LogEntry, LogLevel};
+use super::search::{read_entry_at, LogSearchFilter};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IndexError {
+    #[error(transparent)]
+    Sqlite(#[from] rusqlite::Error),
+}
+
+pub struct AuditIndex {
+    conn: Mutex<Connection>,
+}
+
+impl AuditIndex {
+    pub fn open(db_path: &Path) -> Result<Self, IndexError> {
+        let conn = Connection::open(db_path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS audit_index (
+                id        INTEGER PRIMARY KEY,
+                timestamp TEXT NOT NULL,
+                level     TEXT NOT NULL,
+                action    TEXT NOT NULL,
+                user      TEXT,
+                offset    INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS audit_index_timestamp ON audit_index (timestamp);
+            CREATE INDEX IF NOT EXISTS audit_index_action ON audit_index (action);",
+        )?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    pub fn record(&self, entry: &LogEntry, offset: u64) -> Result<(), IndexError> {
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO audit_index (timestamp, level, action, user, offset) VALUES (?1, ?2, ?3, ?4, ?5)",
+            (entry.timestamp.to_rfc3339(), entry.level.as_str(), &entry.action, &entry.user, offset),
+        )?;
+        Ok(())
+    }
+
+    /// Returns byte offsets of matching rows, most recent first, capped
+    /// at `filter.limit` (0 means unlimited). Every clause is written
+    /// as `(:param IS NULL OR ...)` so one statement serves every
+    /// combination of filters instead of building SQL dynamically.
+    pub fn query_offsets(&self, filter: &LogSearchFilter) -> Result<Vec<u64>, IndexError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT offset FROM audit_index
+             WHERE (:since IS NULL OR timestamp >= :since)
+               AND (:until IS NULL OR timestamp <= :until)
+               AND (:level IS NULL OR level = :level)
+               AND (:action IS NULL OR action LIKE :action)
+               AND (:user IS NULL OR user = :user)
+             ORDER BY id DESC
+             LIMIT :limit",
+        )?;
+
+        let since = filter.since.map(|t| t.to_rfc3339());
+        let until = filter.until.map(|t| t.to_rfc3339());
+        let level = filter.level.map(LogLevel::as_str);
+        let action_like = filter.action_contains.as_ref().map(|needle| format!("%{needle}%"));
+        let limit: i64 = if filter.limit == 0 { -1 } else { filter.limit as i64 };
+
+        let offsets = stmt
+            .query_map(
+                named_params! {
+                    ":since": since,
+                    ":until": until,
+                    ":level": level,
+                    ":action": action_like,
+                    ":user": filter.user,
+                    ":limit": limit,
+                },
+                |row| row.get(0),
+            )?
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        Ok(offsets)
+    }
+
+    /// Same filtering as `query_offsets`, plus keyset pagination on the
+    /// index's own rowid: `before_id` (the last id seen on the previous
+    /// page, `None` for the first page) keeps each page a cheap `id <
+    /// :before_id` scan instead of an `OFFSET` that gets slower the
+    /// deeper the UI pages in.
+    pub fn query_page(
+        &self,
+        filter: &LogSearchFilter,
+        before_id: Option<i64>,
+        page_size: usize,
+    ) -> Result<Vec<(i64, u64)>, IndexError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, offset FROM audit_index
+             WHERE (:since IS NULL OR timestamp >= :since)
+               AND (:until IS NULL OR timestamp <= :until)
+               AND (:level IS NULL OR level = :level)
+               AND (:action IS NULL OR action LIKE :action)
+               AND (:user IS NULL OR user = :user)
+               AND (:before_id IS NULL OR id < :before_id)
+             ORDER BY id DESC
+             LIMIT :page_size",
+        )?;
+
+        let since = filter.since.map(|t| t.to_rfc3339());
+        let until = filter.until.map(|t| t.to_rfc3339());
+        let level = filter.level.map(LogLevel::as_str);
+        let action_like = filter.action_contains.as_ref().map(|needle| format!("%{needle}%"));
+
+        let rows = stmt
+            .query_map(
+                named_params! {
+                    ":since": since,
+                    ":until": until,
+                    ":level": level,
+                    ":action": action_like,
+                    ":user": filter.user,
+                    ":before_id": before_id,
+                    ":page_size": page_size as i64,
+                },
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )?
+            .collect::<Result<Vec<(i64, u64)>, _>>()?;
+
+        Ok(rows)
+    }
+
+    /// Looks up a single entry's byte offset by its index id, for
+    /// `get_audit_entry`.
+    pub fn offset_for_id(&self, id: i64) -> Result<Option<u64>, IndexError> {
+        match self.conn.lock().unwrap().query_row("SELECT offset FROM audit_index WHERE id = ?1", [id], |row| {
+            row.get(0)
+        }) {
+            Ok(offset) => Ok(Some(offset)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn default_path(log_dir: &Path) -> PathBuf {
+        log_dir.join("audit_index.sqlite3")
+    }
+
+    /// Every byte offset currently mirrored, for `reconcile::reconcile_index`
+    /// to diff against what's actually in the log file.
+    pub fn all_offsets(&self) -> Result<std::collections::HashSet<u64>, IndexError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT offset FROM audit_index")?;
+        let offsets = stmt.query_map([], |row| row.get(0))?.collect::<Result<std::collections::HashSet<u64>, _>>()?;
+        Ok(offsets)
+    }
+}
+
+/// One page of indexed audit entries, along with the cursor to pass
+/// back in for the next page (`None` once there's nothing further).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AuditEntryPage {
+    pub entries: Vec<LogEntry>,
+    pub next_cursor: Option<i64>,
+}
+
+/// Resolves one page of `filter` via the index. `log_path` is the file
+/// the offsets resolve against — currently always the main log, since
+/// the index only mirrors that one (see `LogWriter::append`).
+pub fn get_audit_entries_page(
+    index: &AuditIndex,
+    log_path: &Path,
+    filter: &LogSearchFilter,
+    before_id: Option<i64>,
+    page_size: usize,
+) -> Result<AuditEntryPage, IndexError> {
+    let rows = index.query_page(filter, before_id, page_size)?;
+    let next_cursor = if rows.len() == page_size { rows.last().map(|(id, _)| *id) } else { None };
+    let entries = rows.into_iter().filter_map(|(_, offset)| read_entry_at(log_path, offset)).collect();
+    Ok(AuditEntryPage { entries, next_cursor })
+}
+
+/// Resolves a single entry by its index id, for jumping straight to one
+/// entry from e.g. a notification or a shared link.
+pub fn get_audit_entry_by_id(index: &AuditIndex, log_path: &Path, id: i64) -> Result<Option<LogEntry>, IndexError> {
+    Ok(index.offset_for_id(id)?.and_then(|offset| read_entry_at(log_path, offset)))
+}
+
+/// Resolves `filter` to matching entries via the index rather than a
+/// file scan, falling back to whatever offsets the index actually has —
+/// a stale or partially-rebuilt index just returns fewer matches
+/// instead of failing the search.
+pub fn search_logs_indexed(
+    index: &AuditIndex,
+    log_path: &Path,
+    filter: &LogSearchFilter,
+) -> Result<Vec<LogEntry>, IndexError> {
+    let offsets = index.query_offsets(filter)?;
+    Ok(offsets.into_iter().filter_map(|offset| read_entry_at(log_path, offset)).collect())
+}
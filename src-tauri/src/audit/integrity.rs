@@ -0,0 +1,78 @@
+//! Verifies the hash chain `LogWriter` maintains across entries,
+//! detecting insertion, deletion, or editing of any past line — the
+//! auditability guarantee the rest of this module exists to provide.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::entry::{LogEntry, GENESIS_HASH};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainBreak {
+    /// 1-based line number of the broken entry.
+    pub line_number: usize,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegrityReport {
+    pub entries_checked: usize,
+    pub breaks: Vec<ChainBreak>,
+}
+
+impl IntegrityReport {
+    pub fn is_intact(&self) -> bool {
+        self.breaks.is_empty()
+    }
+}
+
+/// Walks `log_path` from the top, recomputing each entry's hash from
+/// its content and the previous entry's hash, and compares it against
+/// what's stored. Each entry's `prev_hash` is checked against the
+/// stored hash of the line before it, so an edited, inserted or removed
+/// line is reported where its link breaks and the scan carries on from
+/// there; every broken line is recorded, so a caller can see the full
+/// extent of the damage rather than just the first symptom.
+pub fn verify_log_integrity(log_path: &Path) -> std::io::Result<IntegrityReport> {
+    let file = File::open(log_path)?;
+    let mut breaks = Vec::new();
+    let mut expected_prev_hash = GENESIS_HASH.to_string();
+    let mut entries_checked = 0;
+
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line_number = index + 1;
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let line = super::encryption::decrypt_if_sealed(&line);
+        let entry: LogEntry = match serde_json::from_str(&line) {
+            Ok(entry) => entry,
+            Err(err) => {
+                breaks.push(ChainBreak { line_number, reason: format!("line is not a valid log entry: {err}") });
+                continue;
+            }
+        };
+        entries_checked += 1;
+
+        if entry.prev_hash != expected_prev_hash {
+            breaks.push(ChainBreak {
+                line_number,
+                reason: "prev_hash does not match the previous entry's hash".to_string(),
+            });
+        }
+
+        let recomputed = entry.compute_hash(&entry.prev_hash);
+        if recomputed != entry.entry_hash {
+            breaks.push(ChainBreak { line_number, reason: "entry_hash does not match its content".to_string() });
+        }
+
+        expected_prev_hash = entry.entry_hash.clone();
+    }
+
+    Ok(IntegrityReport { entries_checked, breaks })
+}
@@ -0,0 +1,89 @@
+//! Counters/gauges for the dashboard, derived straight from entries as
+//! `LogWriter::append` writes them rather than a separate instrumentation
+//! pass scattered through every command — anything worth showing here
+//! already gets written to the audit log anyway.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::entry::{LogEntry, LogLevel};
+
+const COMMAND_RATE_WINDOW_SECS: i64 = 60;
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppMetrics {
+    pub commands_per_minute: f64,
+    pub errors_by_subsystem: HashMap<String, u64>,
+    pub security_events_total: u64,
+}
+
+/// Stand-in "subsystem" for an action, used to bucket error counts.
+/// Actions in this crate are either plain verbs (`rotate_now`,
+/// `verify_admin_code`) or dotted names (`config.changed`,
+/// `crash.panic`), so this takes the segment before the first `.`, `_`
+/// or `:` rather than leaving every error lumped into one unbucketed
+/// total.
+fn subsystem_of(action: &str) -> &str {
+    action.split(['.', '_', ':']).next().unwrap_or(action)
+}
+
+/// Held by `LogWriter` and fed on every append. Unlike `IncrementalStats`
+/// this isn't persisted across restarts — a dashboard gauge resetting on
+/// restart is expected, unlike the audit counters which describe the
+/// log's full history.
+pub struct MetricsTracker {
+    /// Last-seen timestamp per `request_id`, so several entries from one
+    /// command invocation count once toward the rate instead of once
+    /// per entry.
+    recent_commands: Mutex<HashMap<String, DateTime<Utc>>>,
+    errors_by_subsystem: Mutex<HashMap<String, u64>>,
+    security_events_total: Mutex<u64>,
+}
+
+impl MetricsTracker {
+    pub fn new() -> Self {
+        Self {
+            recent_commands: Mutex::new(HashMap::new()),
+            errors_by_subsystem: Mutex::new(HashMap::new()),
+            security_events_total: Mutex::new(0),
+        }
+    }
+
+    pub fn record(&self, entry: &LogEntry) {
+        if let Some(request_id) = &entry.request_id {
+            self.recent_commands.lock().unwrap().insert(request_id.clone(), entry.timestamp);
+        }
+
+        if entry.level == LogLevel::Error {
+            *self.errors_by_subsystem.lock().unwrap().entry(subsystem_of(&entry.action).to_string()).or_insert(0) += 1;
+        }
+
+        if matches!(entry.level, LogLevel::Security | LogLevel::Critical) {
+            *self.security_events_total.lock().unwrap() += 1;
+        }
+    }
+
+    pub fn snapshot(&self) -> AppMetrics {
+        let cutoff = Utc::now() - chrono::Duration::seconds(COMMAND_RATE_WINDOW_SECS);
+        let mut recent = self.recent_commands.lock().unwrap();
+        recent.retain(|_, seen_at| *seen_at >= cutoff);
+        let commands_per_minute = recent.len() as f64;
+        drop(recent);
+
+        AppMetrics {
+            commands_per_minute,
+            errors_by_subsystem: self.errors_by_subsystem.lock().unwrap().clone(),
+            security_events_total: *self.security_events_total.lock().unwrap(),
+        }
+    }
+}
+
+impl Default for MetricsTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
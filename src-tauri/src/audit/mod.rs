@@ -0,0 +1,27 @@
+//! Append-only audit log: every security and memory operation worth
+//! remembering gets written here as a JSON line, independent of
+//! `tracing` output (which is for debugging, not for a record an
+//! admin can search, export, and trust hasn't been tampered with).
+
+pub mod alerts;
+pub mod archive;
+pub mod compression;
+pub mod crash;
+pub mod encryption;
+pub mod entry;
+pub mod event;
+pub mod export;
+pub mod index;
+pub mod integrity;
+pub mod metrics;
+pub mod query;
+pub mod reconcile;
+pub mod redaction;
+pub mod rotation;
+pub mod sampling;
+pub mod search;
+pub mod settings;
+pub mod shipper;
+pub mod signing;
+pub mod stats;
+pub mod writer;
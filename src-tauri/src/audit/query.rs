@@ -0,0 +1,85 @@
+//! A small query language for ad-hoc audit search —
+//! `level:security AND action:*modification* AND ts>2024-01-01` — so
+//! investigating an incident doesn't require exporting and grepping.
+//! Deliberately tiny: clauses joined by the literal ` AND ` (no OR, NOT,
+//! or parentheses), each clause either `field:value` or a `ts>`/`ts<`
+//! timestamp bound. Parses straight into the existing `LogSearchFilter`
+//! rather than a separate AST, since that's all a query here can build.
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+use super::entry::LogLevel;
+use super::search::LogSearchFilter;
+
+#[derive(Debug, thiserror::Error)]
+pub enum QueryParseError {
+    #[error("empty clause in query")]
+    EmptyClause,
+    #[error("unrecognized field `{0}`")]
+    UnknownField(String),
+    #[error("invalid level `{0}`")]
+    InvalidLevel(String),
+    #[error("invalid timestamp `{0}`, expected RFC3339 or YYYY-MM-DD")]
+    InvalidTimestamp(String),
+}
+
+/// Parses `expr` into a `LogSearchFilter`. Repeating a field (e.g. two
+/// `action:` clauses) just has the later one win, the same as building
+/// the filter by hand would.
+pub fn parse_query(expr: &str) -> Result<LogSearchFilter, QueryParseError> {
+    let mut filter = LogSearchFilter::default();
+
+    for clause in expr.split(" AND ") {
+        let clause = clause.trim();
+        if clause.is_empty() {
+            return Err(QueryParseError::EmptyClause);
+        }
+
+        if let Some(rest) = clause.strip_prefix("ts>") {
+            filter.since = Some(parse_timestamp(rest)?);
+        } else if let Some(rest) = clause.strip_prefix("ts<") {
+            filter.until = Some(parse_timestamp(rest)?);
+        } else if let Some((field, value)) = clause.split_once(':') {
+            match field {
+                "level" => filter.level = Some(parse_level(value)?),
+                "action" => filter.action_contains = Some(strip_glob(value)),
+                "user" => filter.user = Some(value.to_string()),
+                other => return Err(QueryParseError::UnknownField(other.to_string())),
+            }
+        } else {
+            return Err(QueryParseError::UnknownField(clause.to_string()));
+        }
+    }
+
+    Ok(filter)
+}
+
+fn parse_level(value: &str) -> Result<LogLevel, QueryParseError> {
+    match value {
+        "trace" => Ok(LogLevel::Trace),
+        "debug" => Ok(LogLevel::Debug),
+        "info" => Ok(LogLevel::Info),
+        "warn" => Ok(LogLevel::Warn),
+        "error" => Ok(LogLevel::Error),
+        "security" => Ok(LogLevel::Security),
+        "critical" => Ok(LogLevel::Critical),
+        other => Err(QueryParseError::InvalidLevel(other.to_string())),
+    }
+}
+
+/// Only a leading/trailing `*` is understood, matching `action_contains`'s
+/// own substring semantics — there's no general glob engine backing this.
+fn strip_glob(value: &str) -> String {
+    value.trim_matches('*').to_string()
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, QueryParseError> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|naive| Utc.from_utc_datetime(&naive))
+        .ok_or_else(|| QueryParseError::InvalidTimestamp(value.to_string()))
+}
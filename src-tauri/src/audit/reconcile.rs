@@ -0,0 +1,83 @@
+//! Detects and repairs divergence between the JSONL file and its
+//! SQLite index mirror. `LogWriter::append` writes both (the file first,
+//! then the index, see `append_raw`'s doc comment), so a crash between the two
+//! leaves the index short of whatever didn't make it across — this
+//! walks the file, the source of truth, and inserts whatever's missing.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::time::interval;
+use tracing::{info, warn};
+
+use super::encryption::decrypt_if_sealed;
+use super::entry::LogEntry;
+use super::index::AuditIndex;
+use super::writer::LogWriter;
+
+/// Reconciliation scans the whole file, so it doesn't need to run nearly
+/// as often as rotation or sampling aggregation — this just needs to
+/// catch up after a crash before the file grows too large to diff cheaply.
+pub const RECONCILIATION_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReconciliationReport {
+    pub entries_scanned: usize,
+    pub missing_from_index: usize,
+    pub repaired: usize,
+}
+
+/// Only ever adds rows to the index — an index row left over with no
+/// corresponding line (a file rewritten out from under it) is a
+/// separate, much rarer problem this doesn't attempt to fix.
+pub fn reconcile_index(index: &AuditIndex, log_path: &Path) -> std::io::Result<ReconciliationReport> {
+    let indexed_offsets: HashSet<u64> = index.all_offsets().unwrap_or_default();
+    let raw = std::fs::read_to_string(log_path)?;
+
+    let mut report = ReconciliationReport::default();
+    let mut offset: u64 = 0;
+
+    for line in raw.split_inclusive('\n') {
+        let line_len = line.len() as u64;
+        let trimmed = line.trim_end_matches('\n');
+        if trimmed.trim().is_empty() {
+            offset += line_len;
+            continue;
+        }
+
+        let decrypted = decrypt_if_sealed(trimmed);
+        if let Ok(entry) = serde_json::from_str::<LogEntry>(&decrypted) {
+            report.entries_scanned += 1;
+            if !indexed_offsets.contains(&offset) {
+                report.missing_from_index += 1;
+                match index.record(&entry, offset) {
+                    Ok(()) => report.repaired += 1,
+                    Err(err) => warn!(%err, offset, "failed to repair audit index entry"),
+                }
+            }
+        }
+        offset += line_len;
+    }
+
+    if report.missing_from_index > 0 {
+        info!(missing = report.missing_from_index, repaired = report.repaired, "reconciled audit index against log file");
+    }
+    Ok(report)
+}
+
+/// Spawns the periodic reconciliation sweep. Meant to be called once
+/// from app setup with the shared `LogWriter` handle, same as
+/// `rotation::spawn` and `sampling::spawn`. A no-op tick for a writer
+/// with no index — `run_reconciliation` just returns `None`.
+pub fn spawn(writer: Arc<LogWriter>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = interval(RECONCILIATION_INTERVAL);
+        loop {
+            ticker.tick().await;
+            writer.run_reconciliation();
+        }
+    })
+}
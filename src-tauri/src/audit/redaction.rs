@@ -0,0 +1,105 @@
+//! Strips common PII patterns out of an entry's free-form fields before
+//! `LogWriter` writes and hashes it, unless the caller has opted into
+//! keeping raw content via `AuditSettings::store_raw_content`. This is a
+//! best-effort regex pass over emails, phone numbers and card-like digit
+//! runs — there's no NLP-based entity recognition in this tree, so names
+//! and addresses embedded in free text won't be caught.
+//!
+//! There's no `log_agent_interaction`/`log_audio_processing` in this
+//! tree for the redaction stage to sit in front of — every caller goes
+//! through `LogWriter::append`, so that's where this hooks in instead.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::entry::LogEntry;
+
+const REDACTED_PLACEHOLDER: &str = "[redacted]";
+
+static EMAIL: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w.+-]+@[\w-]+\.[\w.-]+").unwrap());
+static PHONE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b\+?\d[\d\-. ]{7,}\d\b").unwrap());
+static CARD_NUMBER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(?:\d[ -]?){13,19}\b").unwrap());
+
+fn redact_str(input: &str) -> String {
+    let redacted = EMAIL.replace_all(input, REDACTED_PLACEHOLDER);
+    let redacted = PHONE.replace_all(&redacted, REDACTED_PLACEHOLDER);
+    let redacted = CARD_NUMBER.replace_all(&redacted, REDACTED_PLACEHOLDER);
+    redacted.into_owned()
+}
+
+fn redact_value(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => serde_json::Value::String(redact_str(s)),
+        serde_json::Value::Array(items) => serde_json::Value::Array(items.iter().map(redact_value).collect()),
+        serde_json::Value::Object(map) => {
+            serde_json::Value::Object(map.iter().map(|(k, v)| (k.clone(), redact_value(v))).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Redacts `message` and `metadata` in place unless `store_raw_content`
+/// is set. `action`, `user`, `level` and `timestamp` are left alone —
+/// they're structural fields an operator needs intact to search and
+/// filter, not places a caller would paste a transcript or a form body.
+pub fn redact_entry(entry: &mut LogEntry, store_raw_content: bool) {
+    if store_raw_content {
+        return;
+    }
+    entry.message = redact_str(&entry.message);
+    entry.metadata = redact_value(&entry.metadata);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::entry::LogLevel;
+    use super::*;
+    use chrono::Utc;
+
+    fn entry_with(message: &str, metadata: serde_json::Value) -> LogEntry {
+        LogEntry {
+            timestamp: Utc::now(),
+            level: LogLevel::Info,
+            action: "test.action".to_string(),
+            user: None,
+            message: message.to_string(),
+            metadata,
+            session_id: None,
+            request_id: None,
+            prev_hash: String::new(),
+            entry_hash: String::new(),
+        }
+    }
+
+    #[test]
+    fn an_email_in_the_message_is_redacted() {
+        let mut entry = entry_with("contact alice@example.com for details", serde_json::Value::Null);
+        redact_entry(&mut entry, false);
+        assert!(!entry.message.contains("alice@example.com"));
+        assert!(entry.message.contains(REDACTED_PLACEHOLDER));
+    }
+
+    #[test]
+    fn a_card_like_digit_run_nested_in_metadata_is_redacted() {
+        let mut entry = entry_with(
+            "no pii here",
+            serde_json::json!({ "note": "card 4111 1111 1111 1111 on file" }),
+        );
+        redact_entry(&mut entry, false);
+        assert!(!entry.metadata.to_string().contains("4111 1111 1111 1111"));
+    }
+
+    #[test]
+    fn store_raw_content_skips_redaction_entirely() {
+        let mut entry = entry_with("contact alice@example.com", serde_json::Value::Null);
+        redact_entry(&mut entry, true);
+        assert!(entry.message.contains("alice@example.com"));
+    }
+
+    #[test]
+    fn text_with_no_pii_patterns_is_left_unchanged() {
+        let mut entry = entry_with("nothing sensitive here", serde_json::Value::Null);
+        redact_entry(&mut entry, false);
+        assert_eq!(entry.message, "nothing sensitive here");
+    }
+}
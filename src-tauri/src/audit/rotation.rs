@@ -0,0 +1,185 @@
+//! Periodic background sweep that rotates and prunes the audit log.
+//! Checking file size and pruning old rotated files on every single
+//! `LogWriter::append` would put disk-metadata syscalls on the hot
+//! write path for no benefit — a fixed-interval sweep catches the same
+//! conditions without that cost.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::time::interval;
+use tracing::{info, warn};
+
+use super::entry::{LogEntry, LogLevel};
+use super::settings::AuditSettings;
+use super::stats::rotated_file_inventory;
+use super::writer::LogWriter;
+
+pub const ROTATION_CHECK_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+pub fn check_and_rotate_logs(writer: &LogWriter) -> std::io::Result<Option<PathBuf>> {
+    if writer.should_rotate()? {
+        Ok(Some(writer.rotate_now()?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Splits a just-rotated main-log file into one file per level, so
+/// `cleanup_old_logs` can prune each level against its own retention
+/// window exactly instead of the longest-window approximation used for
+/// a mixed file. Not applied to `security.log`'s rotated files — those
+/// already live in their own file and retention window.
+///
+/// This trades away per-file chain contiguity at the split boundaries:
+/// each entry's `entry_hash` is still verifiable against its own
+/// content, but an entry's `prev_hash` may now name an entry that ended
+/// up in a sibling split file rather than earlier in the same one, so
+/// `verify_log_integrity` will report a break at every entry whose
+/// predecessor went to a different split file, including the first
+/// entry of each one. That's accepted here — exact per-level retention
+/// needs per-level files, and the un-rotated `current.log` is never
+/// split.
+pub fn split_rotated_file_by_level(rotated_path: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let contents = std::fs::read_to_string(rotated_path)?;
+    let mut by_level: std::collections::HashMap<LogLevel, Vec<&str>> = std::collections::HashMap::new();
+
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let plain = super::encryption::decrypt_if_sealed(line);
+        if let Ok(entry) = serde_json::from_str::<LogEntry>(&plain) {
+            by_level.entry(entry.level).or_default().push(line);
+        }
+    }
+
+    let stem = rotated_path.file_stem().and_then(|s| s.to_str()).unwrap_or("audit");
+    let parent = rotated_path.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut written = Vec::new();
+    for (level, lines) in by_level {
+        let split_path = parent.join(format!("{stem}.{}.log", level.as_str()));
+        std::fs::write(&split_path, lines.join("\n") + "\n")?;
+        written.push(split_path);
+    }
+
+    std::fs::remove_file(rotated_path)?;
+    Ok(written)
+}
+
+/// Recovers the level a rotated file was split to, from its
+/// `<name>.<level>.log` suffix — `None` for a mixed file rotated before
+/// splitting existed, or for `security.log`'s own naming.
+fn level_from_split_file_name(file_name: &str) -> Option<LogLevel> {
+    let stem = file_name.strip_suffix(".log")?;
+    let (_, level_str) = stem.rsplit_once('.')?;
+    match level_str {
+        "trace" => Some(LogLevel::Trace),
+        "debug" => Some(LogLevel::Debug),
+        "info" => Some(LogLevel::Info),
+        "warn" => Some(LogLevel::Warn),
+        "error" => Some(LogLevel::Error),
+        _ => None,
+    }
+}
+
+/// Same check as `check_and_rotate_logs`, for `security.log`.
+pub fn check_and_rotate_security_log(writer: &LogWriter) -> std::io::Result<Option<PathBuf>> {
+    if writer.should_rotate_security()? {
+        Ok(Some(writer.rotate_security_now()?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Rotated security-log files are named `security-*.log` (see
+/// `writer::security_rotated_file_name`), distinct from the main log's
+/// `audit-*.log` — that's what lets cleanup apply the longer security
+/// retention window to the right files instead of averaging across
+/// everything in the directory.
+fn is_security_rotated_file(file_name: &str) -> bool {
+    file_name.starts_with("security-")
+}
+
+/// Retention window for a rotated file: its own level's window if it's
+/// been split (or is a `security-*.log` file), or the longest window
+/// among the non-security levels for a mixed file that predates
+/// splitting — the same longest-window approximation used before
+/// per-level files existed, kept as a fallback rather than dropped.
+fn retention_days_for_file(settings: &AuditSettings, file_name: &str) -> u32 {
+    if is_security_rotated_file(file_name) {
+        return settings.retention_days_for(LogLevel::Security).max(settings.retention_days_for(LogLevel::Critical));
+    }
+    if let Some(level) = level_from_split_file_name(file_name) {
+        return settings.retention_days_for(level);
+    }
+    settings
+        .retention_days_by_level
+        .iter()
+        .filter(|(level, _)| *level != LogLevel::Security.as_str() && *level != LogLevel::Critical.as_str())
+        .map(|(_, days)| *days)
+        .max()
+        .unwrap_or(30)
+}
+
+/// Deletes rotated files past their retention window (see
+/// `retention_days_for_file` for how that window is chosen per file).
+pub fn cleanup_old_logs(log_dir: &Path, settings: &AuditSettings) -> std::io::Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+    for file_info in rotated_file_inventory(log_dir) {
+        let retention_days = retention_days_for_file(settings, &file_info.file_name);
+        let cutoff = Utc::now() - chrono::Duration::days(i64::from(retention_days));
+
+        let path = log_dir.join(&file_info.file_name);
+        let Ok(modified) = path.metadata().and_then(|meta| meta.modified()) else { continue };
+        let modified: chrono::DateTime<Utc> = modified.into();
+        if modified < cutoff {
+            std::fs::remove_file(&path)?;
+            removed.push(path);
+        }
+    }
+    Ok(removed)
+}
+
+/// Spawns the rotation+cleanup sweep. Meant to be called once from app
+/// setup with the shared `LogWriter` handle.
+pub fn spawn(writer: Arc<LogWriter>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = interval(ROTATION_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+
+            // Checked before the main log: `rotate_now` marks today as
+            // rotated, which would otherwise hide a due daily rotation
+            // from `should_rotate_security`.
+            match check_and_rotate_security_log(&writer) {
+                Ok(Some(path)) => info!(path = %path.display(), "rotated security log"),
+                Ok(None) => {}
+                Err(err) => warn!(%err, "security log rotation check failed"),
+            }
+
+            match check_and_rotate_logs(&writer) {
+                Ok(Some(path)) => {
+                    info!(path = %path.display(), "rotated audit log");
+                    match split_rotated_file_by_level(&path) {
+                        Ok(split_files) => info!(count = split_files.len(), "split rotated audit log by level"),
+                        Err(err) => warn!(%err, path = %path.display(), "failed to split rotated audit log by level"),
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => warn!(%err, "audit log rotation check failed"),
+            }
+
+            if let Err(err) = cleanup_old_logs(writer.log_dir(), &writer.settings()) {
+                warn!(%err, "audit log cleanup failed");
+            }
+
+            if let Err(err) = writer.stats().persist() {
+                warn!(%err, "failed to persist audit log statistics snapshot");
+            }
+        }
+    })
+}
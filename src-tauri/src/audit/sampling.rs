@@ -0,0 +1,125 @@
+//! High-frequency actions (a UI's waveform or level-meter ticks, say)
+//! would otherwise write one audit line per occurrence and dwarf
+//! everything else in the log. A `SamplingRule` matches entries by
+//! action prefix, lets the first occurrence through, folds the rest
+//! within `window_secs` of it into a count, and appends a single
+//! "N occurrences in window" summary once the window closes instead of
+//! leaving thousands of near-identical rows behind. The log is
+//! append-only, so the first occurrence's own entry stays as written.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::time::interval;
+use tracing::warn;
+
+use super::entry::LogEntry;
+use super::writer::LogWriter;
+
+pub const SAMPLING_FLUSH_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamplingRule {
+    pub action_prefix: String,
+    pub window_secs: i64,
+}
+
+struct OpenWindow {
+    opened_at: DateTime<Utc>,
+    window_secs: i64,
+    count: u64,
+    sample: LogEntry,
+}
+
+/// Owned by a `LogWriter` via `with_sampling`; holds one open window per
+/// matched prefix until `take_due_aggregates` closes it out.
+pub struct Sampler {
+    rules: Mutex<Vec<SamplingRule>>,
+    windows: Mutex<HashMap<String, OpenWindow>>,
+}
+
+impl Sampler {
+    pub fn new(rules: Vec<SamplingRule>) -> Self {
+        Self { rules: Mutex::new(rules), windows: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn rules(&self) -> Vec<SamplingRule> {
+        self.rules.lock().unwrap().clone()
+    }
+
+    /// Replaces the rule set in place — read on the next `admit` call,
+    /// same as `AlertEngine::set_rules`. Leaves any already-open windows
+    /// alone; they close out under whichever rule opened them.
+    pub fn set_rules(&self, rules: Vec<SamplingRule>) {
+        *self.rules.lock().unwrap() = rules;
+    }
+
+    /// `Some(entry)` if this occurrence should be written as-is — no
+    /// rule matched it, or it's the first in a fresh window, so there's
+    /// always at least one real sample on disk per window. `None` if it
+    /// was folded into an already-open window instead.
+    pub fn admit(&self, entry: LogEntry) -> Option<LogEntry> {
+        let rules = self.rules.lock().unwrap();
+        let rule = rules.iter().find(|rule| entry.action.starts_with(rule.action_prefix.as_str()))?.clone();
+        drop(rules);
+        let mut windows = self.windows.lock().unwrap();
+        match windows.get_mut(&rule.action_prefix) {
+            Some(window) if (entry.timestamp - window.opened_at).num_seconds() < window.window_secs => {
+                window.count += 1;
+                window.sample = entry;
+                None
+            }
+            _ => {
+                windows.insert(
+                    rule.action_prefix.clone(),
+                    OpenWindow { opened_at: entry.timestamp, window_secs: rule.window_secs, count: 1, sample: entry.clone() },
+                );
+                Some(entry)
+            }
+        }
+    }
+
+    /// Closes out windows whose span has elapsed, turning each into one
+    /// aggregated entry. A window that only ever admitted its first
+    /// entry (nothing else arrived to fold in) is dropped without an
+    /// aggregate — there's nothing to summarize beyond the entry already
+    /// on disk.
+    pub fn take_due_aggregates(&self) -> Vec<LogEntry> {
+        let now = Utc::now();
+        let mut windows = self.windows.lock().unwrap();
+        let due_prefixes: Vec<String> =
+            windows.iter().filter(|(_, window)| (now - window.opened_at).num_seconds() >= window.window_secs).map(|(prefix, _)| prefix.clone()).collect();
+
+        due_prefixes
+            .into_iter()
+            .filter_map(|prefix| windows.remove(&prefix))
+            .filter(|window| window.count > 1)
+            .map(|window| {
+                let mut entry = window.sample;
+                entry.action = format!("{}.aggregated", entry.action);
+                entry.message = format!("{} occurrences in {}s window", window.count, window.window_secs);
+                entry.metadata = serde_json::json!({ "count": window.count, "window_secs": window.window_secs });
+                entry.timestamp = now;
+                entry
+            })
+            .collect()
+    }
+}
+
+/// Periodically flushes `writer`'s sampler so a window doesn't sit open
+/// forever once its source stops firing — without this, the last burst
+/// before a quiet period would never get its summary line written.
+pub fn spawn(writer: Arc<LogWriter>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = interval(SAMPLING_FLUSH_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = writer.flush_sampling() {
+                warn!(%err, "failed to flush sampling aggregates");
+            }
+        }
+    })
+}
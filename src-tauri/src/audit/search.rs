@@ -0,0 +1,104 @@
+//! Search across the current log file and rotated log files, newest
+//! entry first within each file. Each file the search reaches is read
+//! whole into memory and then scanned from its last line back; the
+//! search stops once it has `limit` matches, so files after that point
+//! are never opened.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use tracing::warn;
+
+use super::compression::open_reader;
+use super::encryption::decrypt_if_sealed;
+use super::entry::{LogEntry, LogLevel};
+
+#[derive(Debug, Clone, Default)]
+pub struct LogSearchFilter {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub action_contains: Option<String>,
+    pub level: Option<LogLevel>,
+    pub user: Option<String>,
+    pub limit: usize,
+}
+
+fn matches(entry: &LogEntry, filter: &LogSearchFilter) -> bool {
+    if let Some(since) = filter.since {
+        if entry.timestamp < since {
+            return false;
+        }
+    }
+    if let Some(until) = filter.until {
+        if entry.timestamp > until {
+            return false;
+        }
+    }
+    if let Some(level) = filter.level {
+        if entry.level != level {
+            return false;
+        }
+    }
+    if let Some(ref needle) = filter.action_contains {
+        if !entry.action.contains(needle.as_str()) {
+            return false;
+        }
+    }
+    if let Some(ref user) = filter.user {
+        if entry.user.as_deref() != Some(user.as_str()) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Scans `log_files` in the order given and returns up to
+/// `filter.limit` matching entries, most recent first within each file;
+/// entries from different files are not merged by timestamp. A line that
+/// fails to parse — a truncated write, a corrupted rotation — is
+/// skipped with a warning rather than aborting the whole search.
+pub fn search_logs(log_files: &[impl AsRef<Path>], filter: &LogSearchFilter) -> Vec<LogEntry> {
+    let mut results = Vec::new();
+    let limit = if filter.limit == 0 { usize::MAX } else { filter.limit };
+
+    for path in log_files {
+        let path = path.as_ref();
+        let Ok(reader) = open_reader(path) else { continue };
+        let lines: Vec<String> = reader.lines().map_while(Result::ok).collect();
+
+        for line in lines.into_iter().rev() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let line = decrypt_if_sealed(&line);
+            match serde_json::from_str::<LogEntry>(&line) {
+                Ok(entry) => {
+                    if matches(&entry, filter) {
+                        results.push(entry);
+                        if results.len() >= limit {
+                            return results;
+                        }
+                    }
+                }
+                Err(err) => warn!(%err, path = %path.display(), "skipping corrupt log line"),
+            }
+        }
+    }
+
+    results
+}
+
+/// Reads and parses the single JSONL line starting at `offset` in
+/// `log_path` — the read path for `AuditIndex::query_offsets`, which
+/// resolves matches to positions rather than entries.
+pub fn read_entry_at(log_path: &Path, offset: u64) -> Option<LogEntry> {
+    let mut file = File::open(log_path).ok()?;
+    file.seek(SeekFrom::Start(offset)).ok()?;
+    let mut reader = BufReader::new(file);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let line = decrypt_if_sealed(line.trim_end());
+    serde_json::from_str(&line).ok()
+}
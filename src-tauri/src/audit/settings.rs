@@ -0,0 +1,88 @@
+//! Tunable knobs for the audit log — size and retention used to be
+//! hardcoded in `LogWriter::open`. These settings are not part of
+//! `config::AppConfig` and are not loaded from or saved to the config
+//! file; this follows the same shape as
+//! `security::settings::SecuritySettings`: a plain struct held behind a
+//! `RwLock` on the owning type, swappable at runtime.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::entry::LogLevel;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RotationSchedule {
+    /// Rotate once the current file crosses `max_log_size_mb`, and also
+    /// once a day regardless of size.
+    Daily,
+    /// Rotate on size alone; never rotate purely for age.
+    SizeOnly,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditSettings {
+    pub max_log_size_mb: u64,
+    /// Keyed by `LogLevel::as_str()` rather than `LogLevel` itself, the
+    /// same reasoning as `LogCounters`: keeps the JSON shape obvious at
+    /// the wire boundary instead of relying on enum-as-map-key derive
+    /// behavior.
+    pub retention_days_by_level: HashMap<String, u32>,
+    pub rotation_schedule: RotationSchedule,
+    /// Opts out of the PII redaction pass in `redaction::redact_entry`,
+    /// keeping messages and metadata exactly as callers supplied them.
+    /// Off by default — an admin has to explicitly decide raw content
+    /// is worth the exposure.
+    pub store_raw_content: bool,
+}
+
+const ALL_LEVELS: [LogLevel; 7] = [
+    LogLevel::Trace,
+    LogLevel::Debug,
+    LogLevel::Info,
+    LogLevel::Warn,
+    LogLevel::Error,
+    LogLevel::Security,
+    LogLevel::Critical,
+];
+
+impl Default for AuditSettings {
+    fn default() -> Self {
+        let retention_days_by_level = ALL_LEVELS
+            .into_iter()
+            .map(|level| {
+                let days = match level {
+                    LogLevel::Trace | LogLevel::Debug => 7,
+                    LogLevel::Info => 30,
+                    LogLevel::Warn => 90,
+                    LogLevel::Error => 365,
+                    // security.log is where an incident investigation
+                    // starts months later, so it outlives every other
+                    // level by default.
+                    LogLevel::Security => 730,
+                    LogLevel::Critical => 1825,
+                };
+                (level.as_str().to_string(), days)
+            })
+            .collect();
+
+        Self {
+            max_log_size_mb: 100,
+            retention_days_by_level,
+            rotation_schedule: RotationSchedule::Daily,
+            store_raw_content: false,
+        }
+    }
+}
+
+impl AuditSettings {
+    pub fn retention_days_for(&self, level: LogLevel) -> u32 {
+        self.retention_days_by_level.get(level.as_str()).copied().unwrap_or(30)
+    }
+
+    pub fn max_log_size_bytes(&self) -> u64 {
+        self.max_log_size_mb * 1024 * 1024
+    }
+}
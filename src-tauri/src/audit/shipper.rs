@@ -0,0 +1,176 @@
+//! Forwards audit entries to a remote sink for users centralizing logs
+//! from several machines. Delivery failures (the sink is unreachable,
+//! this machine is offline) spool entries to disk instead of dropping
+//! them, the same way `LogWriter` never loses an entry it's accepted —
+//! a background task drains the spool once the sink is reachable again.
+
+use std::io::Write;
+use std::net::{TcpStream, UdpSocket};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::interval;
+use tracing::{info, warn};
+
+use super::entry::LogEntry;
+
+const SPOOL_FILE_NAME: &str = "shipper.spool.jsonl";
+const RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub enum RemoteSink {
+    SyslogUdp { addr: String },
+    SyslogTcp { addr: String },
+    Http { url: String },
+    /// A minimal OTLP/HTTP logs payload rather than full OTLP/gRPC —
+    /// enough for collectors that accept OTLP over HTTP+JSON.
+    Otlp { endpoint: String },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ShipError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+}
+
+pub struct LogShipper {
+    sink: RemoteSink,
+    client: reqwest::Client,
+    spool_path: PathBuf,
+    spool_lock: Mutex<()>,
+}
+
+impl LogShipper {
+    pub fn new(sink: RemoteSink, spool_dir: &Path) -> Self {
+        Self {
+            sink,
+            client: reqwest::Client::new(),
+            spool_path: spool_dir.join(SPOOL_FILE_NAME),
+            spool_lock: Mutex::new(()),
+        }
+    }
+
+    /// Attempts immediate delivery; on failure, appends the entry to
+    /// the spool file rather than returning an error, since a shipper
+    /// is a best-effort side channel and must never block `LogWriter`.
+    pub async fn ship(&self, entry: &LogEntry) {
+        match self.send(entry).await {
+            Ok(()) => {}
+            Err(err) => {
+                warn!(%err, "failed to ship audit entry, spooling for retry");
+                if let Err(spool_err) = self.spool(entry) {
+                    warn!(%spool_err, "failed to spool audit entry after failed shipment");
+                }
+            }
+        }
+    }
+
+    async fn send(&self, entry: &LogEntry) -> Result<(), ShipError> {
+        match &self.sink {
+            RemoteSink::SyslogUdp { addr } => {
+                let socket = UdpSocket::bind("0.0.0.0:0")?;
+                socket.send_to(syslog_message(entry).as_bytes(), addr)?;
+            }
+            RemoteSink::SyslogTcp { addr } => {
+                let mut stream = TcpStream::connect(addr)?;
+                stream.write_all(syslog_message(entry).as_bytes())?;
+            }
+            RemoteSink::Http { url } => {
+                self.client.post(url).json(entry).send().await?.error_for_status()?;
+            }
+            RemoteSink::Otlp { endpoint } => {
+                self.client.post(endpoint).json(&otlp_log_record(entry)).send().await?.error_for_status()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn spool(&self, entry: &LogEntry) -> std::io::Result<()> {
+        let _guard = self.spool_lock.lock().unwrap();
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.spool_path)?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)
+    }
+
+    /// Replays every spooled entry, keeping in the spool only the ones
+    /// that still fail — called periodically by `spawn_retry_task` and
+    /// exposed directly for a manual "retry now" command.
+    pub async fn drain_spool(&self) -> std::io::Result<usize> {
+        let (spooled, spooled_len): (Vec<LogEntry>, usize) = {
+            let _guard = self.spool_lock.lock().unwrap();
+            let Ok(raw) = std::fs::read_to_string(&self.spool_path) else { return Ok(0) };
+            let spooled = raw.lines().filter(|l| !l.trim().is_empty()).filter_map(|l| serde_json::from_str(l).ok()).collect();
+            (spooled, raw.len())
+        };
+        if spooled.is_empty() {
+            return Ok(0);
+        }
+
+        let mut still_failing = Vec::new();
+        for entry in &spooled {
+            if self.send(entry).await.is_err() {
+                still_failing.push(entry);
+            }
+        }
+
+        let delivered = spooled.len() - still_failing.len();
+        let _guard = self.spool_lock.lock().unwrap();
+        let mut rewritten: String =
+            still_failing.iter().filter_map(|e| serde_json::to_string(e).ok()).map(|l| l + "\n").collect();
+        // Entries spooled while the lock was released for sending sit
+        // after the bytes read above; carry them over.
+        if let Ok(current) = std::fs::read_to_string(&self.spool_path) {
+            if let Some(appended) = current.get(spooled_len..) {
+                rewritten.push_str(appended);
+            }
+        }
+        std::fs::write(&self.spool_path, rewritten)?;
+
+        Ok(delivered)
+    }
+}
+
+/// A compact RFC 5424-style message — enough for most syslog receivers
+/// without pulling in a dedicated crate for the full spec.
+fn syslog_message(entry: &LogEntry) -> String {
+    format!(
+        "<14>1 {} dyht audit - - - {}",
+        entry.timestamp.to_rfc3339(),
+        serde_json::to_string(entry).unwrap_or_default()
+    )
+}
+
+fn otlp_log_record(entry: &LogEntry) -> serde_json::Value {
+    serde_json::json!({
+        "resourceLogs": [{
+            "scopeLogs": [{
+                "logRecords": [{
+                    "timeUnixNano": entry.timestamp.timestamp_nanos_opt().unwrap_or(0).to_string(),
+                    "severityText": entry.level.as_str(),
+                    "body": { "stringValue": entry.message },
+                    "attributes": [
+                        { "key": "action", "value": { "stringValue": entry.action } },
+                        { "key": "user", "value": { "stringValue": entry.user.clone().unwrap_or_default() } },
+                    ],
+                }]
+            }]
+        }]
+    })
+}
+
+/// Spawns the periodic spool-retry sweep.
+pub fn spawn_retry_task(shipper: std::sync::Arc<LogShipper>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = interval(RETRY_INTERVAL);
+        loop {
+            ticker.tick().await;
+            match shipper.drain_spool().await {
+                Ok(0) => {}
+                Ok(count) => info!(count, "delivered spooled audit entries"),
+                Err(err) => warn!(%err, "failed to drain audit shipper spool"),
+            }
+        }
+    })
+}
@@ -0,0 +1,88 @@
+//! Ed25519 signing of completed log files, so a rotated log exported
+//! off this machine can be proven authentic to a third party rather
+//! than just internally consistent (which is all the hash chain in
+//! `integrity` gives you). The signing key lives in the OS keyring like
+//! every other secret this crate holds — see `security::keyring_store`.
+
+use std::fs;
+use std::path::Path;
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+
+use crate::security::keyring_store::{self, KeyringError};
+
+const SIGNING_KEY_SECRET: &str = "audit_log_signing_key";
+pub const SIGNATURE_FILE_SUFFIX: &str = ".sig";
+
+#[derive(Debug, thiserror::Error)]
+pub enum SigningError {
+    #[error(transparent)]
+    Keyring(#[from] KeyringError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("no audit signing key has been generated yet")]
+    NoKey,
+    #[error("signature is malformed")]
+    MalformedSignature,
+    #[error("signature does not match file contents")]
+    InvalidSignature,
+}
+
+/// Loads the signing key from the keyring, generating and storing one
+/// on first use — there's nothing for an admin to configure here.
+fn load_or_create_signing_key() -> Result<SigningKey, SigningError> {
+    if let Some(encoded) = keyring_store::get_secret(SIGNING_KEY_SECRET)? {
+        let bytes = hex::decode(encoded).map_err(|_| SigningError::NoKey)?;
+        let bytes: [u8; 32] = bytes.try_into().map_err(|_| SigningError::NoKey)?;
+        return Ok(SigningKey::from_bytes(&bytes));
+    }
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    keyring_store::set_secret(SIGNING_KEY_SECRET, &hex::encode(signing_key.to_bytes()))?;
+    Ok(signing_key)
+}
+
+/// Returns the public key an admin can hand to a third party to verify
+/// exported logs independently of this machine's keyring.
+pub fn verifying_key_hex() -> Result<String, SigningError> {
+    Ok(hex::encode(load_or_create_signing_key()?.verifying_key().to_bytes()))
+}
+
+/// Signs `file_path` and writes the detached signature alongside it at
+/// `file_path` + `.sig`, hex-encoded.
+pub fn sign_file(file_path: &Path) -> Result<(), SigningError> {
+    let signing_key = load_or_create_signing_key()?;
+    let content = fs::read(file_path)?;
+    let signature = signing_key.sign(&content);
+
+    let sig_path = signature_path(file_path);
+    fs::write(sig_path, hex::encode(signature.to_bytes()))?;
+    Ok(())
+}
+
+/// Verifies `file_path` against its detached `.sig` signature. Returns
+/// `Ok(())` when valid; any other outcome — missing signature, bad
+/// hex, mismatched content — is an error describing why verification
+/// failed rather than a bare boolean.
+pub fn verify_file(file_path: &Path) -> Result<(), SigningError> {
+    let verifying_key_bytes =
+        hex::decode(verifying_key_hex()?).map_err(|_| SigningError::MalformedSignature)?;
+    let verifying_key_bytes: [u8; 32] =
+        verifying_key_bytes.try_into().map_err(|_| SigningError::MalformedSignature)?;
+    let verifying_key = VerifyingKey::from_bytes(&verifying_key_bytes).map_err(|_| SigningError::MalformedSignature)?;
+
+    let sig_hex = fs::read_to_string(signature_path(file_path))?;
+    let sig_bytes = hex::decode(sig_hex.trim()).map_err(|_| SigningError::MalformedSignature)?;
+    let sig_bytes: [u8; 64] = sig_bytes.try_into().map_err(|_| SigningError::MalformedSignature)?;
+    let signature = Signature::from_bytes(&sig_bytes);
+
+    let content = fs::read(file_path)?;
+    verifying_key.verify(&content, &signature).map_err(|_| SigningError::InvalidSignature)
+}
+
+fn signature_path(file_path: &Path) -> std::path::PathBuf {
+    let mut name = file_path.as_os_str().to_os_string();
+    name.push(SIGNATURE_FILE_SUFFIX);
+    std::path::PathBuf::from(name)
+}
@@ -0,0 +1,143 @@
+//! Log statistics maintained incrementally as entries are written,
+//! rather than recomputed by scanning every log file on each request —
+//! `get_log_statistics` on a multi-gigabyte history needs to be cheap.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::entry::LogEntry;
+use super::reconcile::ReconciliationReport;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogCounters {
+    pub counts_by_level: HashMap<String, u64>,
+    pub counts_by_action: HashMap<String, u64>,
+    /// Keyed by ISO `YYYY-MM-DD` date rather than `NaiveDate` directly,
+    /// since JSON object keys must be strings.
+    pub counts_by_day: HashMap<String, u64>,
+    pub oldest: Option<DateTime<Utc>>,
+    pub newest: Option<DateTime<Utc>>,
+    pub total_entries: u64,
+}
+
+impl LogCounters {
+    fn record(&mut self, entry: &LogEntry) {
+        *self.counts_by_level.entry(entry.level.as_str().to_string()).or_insert(0) += 1;
+        *self.counts_by_action.entry(entry.action.clone()).or_insert(0) += 1;
+        *self.counts_by_day.entry(entry.timestamp.date_naive().to_string()).or_insert(0) += 1;
+        self.oldest = Some(self.oldest.map_or(entry.timestamp, |o| o.min(entry.timestamp)));
+        self.newest = Some(self.newest.map_or(entry.timestamp, |n| n.max(entry.timestamp)));
+        self.total_entries += 1;
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RotatedFileInfo {
+    pub file_name: String,
+    pub size_bytes: u64,
+    /// Size before zstd compression, if this file was compressed and
+    /// its sizing sidecar is still present.
+    pub original_size_bytes: Option<u64>,
+    pub compressed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogStatistics {
+    #[serde(flatten)]
+    pub counters: LogCounters,
+    pub rotated_files: Vec<RotatedFileInfo>,
+    /// Outcome of the most recent index/file reconciliation sweep, if
+    /// the writer has run one — see `audit::reconcile`.
+    pub last_reconciliation: Option<ReconciliationReport>,
+}
+
+/// Holds the running counters in memory and persists a snapshot to
+/// disk periodically (see `audit::rotation`'s background task), rather
+/// than on every single write.
+pub struct IncrementalStats {
+    state: RwLock<LogCounters>,
+    snapshot_path: PathBuf,
+}
+
+impl IncrementalStats {
+    pub fn load_or_default(snapshot_path: PathBuf) -> Self {
+        let state = std::fs::read_to_string(&snapshot_path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+        Self { state: RwLock::new(state), snapshot_path }
+    }
+
+    pub fn record(&self, entry: &LogEntry) {
+        self.state.write().unwrap().record(entry);
+    }
+
+    pub fn counters(&self) -> LogCounters {
+        self.state.read().unwrap().clone()
+    }
+
+    pub fn persist(&self) -> std::io::Result<()> {
+        let snapshot = self.counters();
+        let raw = serde_json::to_string(&snapshot)?;
+        std::fs::write(&self.snapshot_path, raw)
+    }
+}
+
+/// File name suffixes in `log_dir` that support the log but aren't
+/// themselves rotated log files — sidecars for signing and compression
+/// sizing. The stats snapshot and the index are excluded by name, and
+/// anything not named `audit-*`/`security-*` (the crash-recovery marker
+/// and reports, the shipper spool) by prefix, in `is_rotated_log_file`.
+const NON_LOG_SUFFIXES: &[&str] = &[
+    super::signing::SIGNATURE_FILE_SUFFIX,
+    ".meta.json",
+];
+
+fn is_rotated_log_file(file_name: &str) -> bool {
+    (file_name.starts_with("audit-") || file_name.starts_with("security-"))
+        && file_name != super::writer::CURRENT_LOG_FILE_NAME
+        && file_name != super::writer::SECURITY_LOG_FILE_NAME
+        && file_name != "stats.snapshot.json"
+        && file_name != "audit_index.sqlite3"
+        && !NON_LOG_SUFFIXES.iter().any(|suffix| file_name.ends_with(suffix))
+}
+
+/// Lists rotated log files in `log_dir` with their sizes. Computed
+/// fresh on every call (a directory listing is cheap) rather than kept
+/// in the incremental counters.
+pub fn rotated_file_inventory(log_dir: &Path) -> Vec<RotatedFileInfo> {
+    let Ok(read_dir) = std::fs::read_dir(log_dir) else { return Vec::new() };
+
+    let mut files: Vec<RotatedFileInfo> = read_dir
+        .filter_map(Result::ok)
+        .filter(|entry| is_rotated_log_file(&entry.file_name().to_string_lossy()))
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            if !metadata.is_file() {
+                return None;
+            }
+            let path = entry.path();
+            Some(RotatedFileInfo {
+                file_name: entry.file_name().to_string_lossy().into_owned(),
+                size_bytes: metadata.len(),
+                original_size_bytes: super::compression::original_size_bytes(&path),
+                compressed: super::compression::is_compressed(&path),
+            })
+        })
+        .collect();
+
+    files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+    files
+}
+
+pub fn get_log_statistics(
+    stats: &IncrementalStats,
+    log_dir: &Path,
+    last_reconciliation: Option<ReconciliationReport>,
+) -> LogStatistics {
+    LogStatistics { counters: stats.counters(), rotated_files: rotated_file_inventory(log_dir), last_reconciliation }
+}
@@ -0,0 +1,407 @@
+//! Appends entries to the current log file as JSON lines, chaining
+//! each one to the last via `LogEntry::compute_hash` so the file is
+//! tamper-evident. Rotation, compression and signing live in their own
+//! modules added alongside this one — the writer's only job is getting
+//! a correctly-chained line onto disk durably.
+
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+
+use chrono::{NaiveDate, Utc};
+use tauri::{AppHandle, Emitter};
+use tracing::warn;
+
+use super::alerts::AlertEngine;
+use super::encryption::encrypt_line;
+use super::entry::{LogEntry, LogLevel, GENESIS_HASH};
+use super::index::AuditIndex;
+use super::metrics::{AppMetrics, MetricsTracker};
+use super::reconcile::{self, ReconciliationReport};
+use super::redaction::redact_entry;
+use super::sampling::Sampler;
+use super::settings::{AuditSettings, RotationSchedule};
+use super::shipper::LogShipper;
+use super::stats::IncrementalStats;
+
+pub const CURRENT_LOG_FILE_NAME: &str = "current.log";
+/// Dedicated file for `Security`/`Critical` entries — kept separate so
+/// it can be retained far longer than routine activity without bloating
+/// the main log, per `is_security_level`.
+pub const SECURITY_LOG_FILE_NAME: &str = "security.log";
+const STATS_SNAPSHOT_FILE_NAME: &str = "stats.snapshot.json";
+
+/// Whether `level` is routed to `security.log` instead of the main log.
+fn is_security_level(level: LogLevel) -> bool {
+    matches!(level, LogLevel::Security | LogLevel::Critical)
+}
+
+/// Event name the frontend subscribes to for a live activity feed.
+/// Level filtering happens on the subscriber's side — every entry is
+/// emitted regardless of level, same as every entry is written to disk.
+pub const LIVE_ENTRY_EVENT: &str = "audit://entry";
+
+pub struct LogWriter {
+    log_dir: PathBuf,
+    file: Mutex<std::fs::File>,
+    last_hash: Mutex<String>,
+    /// Security/Critical entries chain independently of the main log —
+    /// it's a separate file, so splicing its hashes into the main
+    /// chain (or vice versa) would make either one unverifiable on its
+    /// own.
+    security_file: Mutex<std::fs::File>,
+    security_last_hash: Mutex<String>,
+    stats: IncrementalStats,
+    metrics: MetricsTracker,
+    index: Option<AuditIndex>,
+    settings: RwLock<AuditSettings>,
+    last_rotated_on: Mutex<NaiveDate>,
+    app_handle: Option<AppHandle>,
+    shipper: Option<Arc<LogShipper>>,
+    alerts: Option<Arc<AlertEngine>>,
+    sampler: Option<Sampler>,
+    encrypt_at_rest: bool,
+    last_reconciliation: Mutex<Option<ReconciliationReport>>,
+}
+
+impl LogWriter {
+    pub fn open(log_dir: &Path) -> std::io::Result<Self> {
+        std::fs::create_dir_all(log_dir)?;
+        let log_path = log_dir.join(CURRENT_LOG_FILE_NAME);
+        let last_hash = last_entry_hash(&log_path).unwrap_or_else(|| GENESIS_HASH.to_string());
+        let file = OpenOptions::new().create(true).append(true).open(&log_path)?;
+
+        let security_log_path = log_dir.join(SECURITY_LOG_FILE_NAME);
+        let security_last_hash =
+            last_entry_hash(&security_log_path).unwrap_or_else(|| GENESIS_HASH.to_string());
+        let security_file = OpenOptions::new().create(true).append(true).open(&security_log_path)?;
+
+        let stats = IncrementalStats::load_or_default(log_dir.join(STATS_SNAPSHOT_FILE_NAME));
+        Ok(Self {
+            log_dir: log_dir.to_path_buf(),
+            file: Mutex::new(file),
+            last_hash: Mutex::new(last_hash),
+            security_file: Mutex::new(security_file),
+            security_last_hash: Mutex::new(security_last_hash),
+            stats,
+            metrics: MetricsTracker::new(),
+            index: None,
+            settings: RwLock::new(AuditSettings::default()),
+            // Treat startup as the day's rotation point, so a restart
+            // doesn't immediately trigger a daily rotation.
+            last_rotated_on: Mutex::new(Utc::now().date_naive()),
+            app_handle: None,
+            shipper: None,
+            alerts: None,
+            sampler: None,
+            encrypt_at_rest: false,
+            last_reconciliation: Mutex::new(None),
+        })
+    }
+
+    /// Opens (or creates) the SQLite mirror alongside the log file. A
+    /// writer without an index still works — callers fall back to a
+    /// full file scan — so a failure to open SQLite here is logged
+    /// rather than propagated.
+    pub fn with_index(mut self) -> Self {
+        match AuditIndex::open(&AuditIndex::default_path(&self.log_dir)) {
+            Ok(index) => self.index = Some(index),
+            Err(err) => warn!(%err, "failed to open audit index, falling back to file scans"),
+        }
+        self
+    }
+
+    /// Enables live streaming: every entry appended after this is also
+    /// emitted as a `LIVE_ENTRY_EVENT` Tauri event. Without it, the
+    /// writer still works — there's just no frontend to tell.
+    pub fn with_app_handle(mut self, app_handle: AppHandle) -> Self {
+        self.app_handle = Some(app_handle);
+        self
+    }
+
+    /// Enables forwarding every appended entry to a remote sink via the
+    /// given shipper. Delivery runs on the async runtime rather than
+    /// inline, so a slow or unreachable remote never blocks a write.
+    pub fn with_shipper(mut self, shipper: Arc<LogShipper>) -> Self {
+        self.shipper = Some(shipper);
+        self
+    }
+
+    /// Enables alert evaluation: every appended entry is checked against
+    /// the engine's rules. Without it, the writer still works — there
+    /// are just no alert rules to trip.
+    pub fn with_alerts(mut self, alerts: Arc<AlertEngine>) -> Self {
+        self.alerts = Some(alerts);
+        self
+    }
+
+    /// Enables per-action sampling: entries matching one of `rules`'
+    /// prefixes are folded into windowed "N occurrences" summaries
+    /// instead of being written individually. Without it, every entry
+    /// is written as-is, same as before sampling existed.
+    pub fn with_sampling(mut self, rules: Vec<super::sampling::SamplingRule>) -> Self {
+        self.sampler = Some(Sampler::new(rules));
+        self
+    }
+
+    /// Enables encryption at rest: every entry appended afterward is
+    /// sealed under a keyring-held key before it's written. Existing
+    /// plaintext lines already on disk are left as they are — search
+    /// and export handle a file mixing both transparently, see
+    /// `encryption::decrypt_if_sealed`.
+    pub fn with_encryption(mut self) -> Self {
+        self.encrypt_at_rest = true;
+        self
+    }
+
+    pub fn append(&self, entry: &LogEntry) -> std::io::Result<()> {
+        match &self.sampler {
+            Some(sampler) => match sampler.admit(entry.clone()) {
+                Some(entry) => self.append_raw(&entry),
+                None => Ok(()),
+            },
+            None => self.append_raw(entry),
+        }
+    }
+
+    /// Closes out any sampling windows past their deadline, writing one
+    /// aggregated entry per window via `append_raw` — bypassing
+    /// `append`'s sampler check, since an aggregate already represents a
+    /// closed window rather than a fresh occurrence to fold.
+    pub fn flush_sampling(&self) -> std::io::Result<()> {
+        let Some(sampler) = &self.sampler else { return Ok(()) };
+        for entry in sampler.take_due_aggregates() {
+            self.append_raw(&entry)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `entry` to the JSONL file, then mirrors it into the index.
+    /// The file write is the one that's durable and authoritative; the
+    /// index write is best-effort and can't un-happen the file write if
+    /// it fails or the process dies in between, which is exactly the gap
+    /// `reconcile::reconcile_index` exists to close on the next sweep.
+    fn append_raw(&self, entry: &LogEntry) -> std::io::Result<()> {
+        let (file_lock, last_hash_lock) =
+            if is_security_level(entry.level) { (&self.security_file, &self.security_last_hash) } else { (&self.file, &self.last_hash) };
+
+        let mut last_hash = last_hash_lock.lock().unwrap();
+        let mut entry = entry.clone();
+        redact_entry(&mut entry, self.settings().store_raw_content);
+        entry.prev_hash = last_hash.clone();
+        entry.entry_hash = entry.compute_hash(&last_hash);
+
+        let line = serde_json::to_string(&entry)?;
+        let line = if self.encrypt_at_rest {
+            match encrypt_line(&line) {
+                Ok(sealed) => sealed,
+                Err(err) => {
+                    warn!(%err, "failed to encrypt audit log line, writing it in the clear");
+                    line
+                }
+            }
+        } else {
+            line
+        };
+        let mut file = file_lock.lock().unwrap();
+        let offset = file.stream_position()?;
+        writeln!(file, "{line}")?;
+        file.flush()?;
+        drop(file);
+
+        *last_hash = entry.entry_hash.clone();
+        drop(last_hash);
+
+        // Snapshot persistence to disk is periodic, not per-write — see
+        // the background rotation task.
+        self.stats.record(&entry);
+        self.metrics.record(&entry);
+
+        // The index resolves offsets against `current_path()` alone, so
+        // only main-log entries can be mirrored into it until the index
+        // learns to track multiple source files.
+        if !is_security_level(entry.level) {
+            if let Some(ref index) = self.index {
+                if let Err(err) = index.record(&entry, offset) {
+                    warn!(%err, "failed to mirror audit entry into index");
+                }
+            }
+        }
+
+        if let Some(ref app_handle) = self.app_handle {
+            if let Err(err) = app_handle.emit(LIVE_ENTRY_EVENT, &entry) {
+                warn!(%err, "failed to emit live audit entry event");
+            }
+        }
+
+        if let Some(ref shipper) = self.shipper {
+            let shipper = Arc::clone(shipper);
+            let entry = entry.clone();
+            tokio::spawn(async move { shipper.ship(&entry).await });
+        }
+
+        if let Some(ref alerts) = self.alerts {
+            alerts.check(&entry);
+        }
+
+        Ok(())
+    }
+
+    pub fn log_dir(&self) -> &Path {
+        &self.log_dir
+    }
+
+    pub fn current_path(&self) -> PathBuf {
+        self.log_dir.join(CURRENT_LOG_FILE_NAME)
+    }
+
+    pub fn security_path(&self) -> PathBuf {
+        self.log_dir.join(SECURITY_LOG_FILE_NAME)
+    }
+
+    pub fn stats(&self) -> &IncrementalStats {
+        &self.stats
+    }
+
+    pub fn metrics(&self) -> AppMetrics {
+        self.metrics.snapshot()
+    }
+
+    pub fn index(&self) -> Option<&AuditIndex> {
+        self.index.as_ref()
+    }
+
+    pub fn shipper(&self) -> Option<&Arc<LogShipper>> {
+        self.shipper.as_ref()
+    }
+
+    pub fn alerts(&self) -> Option<&Arc<AlertEngine>> {
+        self.alerts.as_ref()
+    }
+
+    pub fn sampler(&self) -> Option<&Sampler> {
+        self.sampler.as_ref()
+    }
+
+    pub fn encrypt_at_rest(&self) -> bool {
+        self.encrypt_at_rest
+    }
+
+    /// Diffs the index against the main log file and repairs whatever's
+    /// missing, recording the outcome for `last_reconciliation` to
+    /// report. A writer without an index has nothing to reconcile.
+    pub fn run_reconciliation(&self) -> Option<ReconciliationReport> {
+        let index = self.index.as_ref()?;
+        let report = match reconcile::reconcile_index(index, &self.current_path()) {
+            Ok(report) => report,
+            Err(err) => {
+                warn!(%err, "audit index reconciliation failed");
+                return None;
+            }
+        };
+        *self.last_reconciliation.lock().unwrap() = Some(report.clone());
+        Some(report)
+    }
+
+    pub fn last_reconciliation(&self) -> Option<ReconciliationReport> {
+        self.last_reconciliation.lock().unwrap().clone()
+    }
+
+    pub fn settings(&self) -> AuditSettings {
+        self.settings.read().unwrap().clone()
+    }
+
+    /// Replaces the size/retention/rotation settings in place — read by
+    /// the rotation task on its next tick, so changes apply without a
+    /// restart.
+    pub fn set_settings(&self, settings: AuditSettings) {
+        *self.settings.write().unwrap() = settings;
+    }
+
+    /// Whether the current file has crossed the configured size limit,
+    /// or — on a `Daily` schedule — whether it's still open from an
+    /// earlier calendar day.
+    pub fn should_rotate(&self) -> std::io::Result<bool> {
+        let size = self.file.lock().unwrap().metadata()?.len();
+        self.crossed_rotation_threshold(size)
+    }
+
+    /// Same check as `should_rotate`, against `security.log` instead —
+    /// it follows the same size/daily policy as the main log rather
+    /// than a separate one, since `AuditSettings` only configures one
+    /// rotation schedule.
+    pub fn should_rotate_security(&self) -> std::io::Result<bool> {
+        let size = self.security_file.lock().unwrap().metadata()?.len();
+        self.crossed_rotation_threshold(size)
+    }
+
+    fn crossed_rotation_threshold(&self, size: u64) -> std::io::Result<bool> {
+        let settings = self.settings();
+        if size >= settings.max_log_size_bytes() {
+            return Ok(true);
+        }
+        if settings.rotation_schedule == RotationSchedule::Daily {
+            let today = Utc::now().date_naive();
+            if *self.last_rotated_on.lock().unwrap() != today {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Renames the current file to a timestamped rotated name and
+    /// starts a fresh one. The hash chain isn't reset — the first entry
+    /// in the new file still chains from the last entry in the old one.
+    /// `verify_log_integrity` checks a single file starting from
+    /// `GENESIS_HASH`, so it reports a break at the first entry of any
+    /// file that follows a rotation.
+    pub fn rotate_now(&self) -> std::io::Result<PathBuf> {
+        let rotated_path = self.log_dir.join(rotated_file_name(Utc::now()));
+        rotate_file(&self.file, &self.current_path(), &rotated_path)?;
+        *self.last_rotated_on.lock().unwrap() = Utc::now().date_naive();
+        Ok(rotated_path)
+    }
+
+    /// Rotates `security.log` the same way `rotate_now` rotates the
+    /// main log, with its own hash chain and file left untouched by the
+    /// main log's rotation bookkeeping.
+    pub fn rotate_security_now(&self) -> std::io::Result<PathBuf> {
+        let rotated_path = self.log_dir.join(security_rotated_file_name(Utc::now()));
+        rotate_file(&self.security_file, &self.security_path(), &rotated_path)?;
+        Ok(rotated_path)
+    }
+}
+
+fn rotate_file(file: &Mutex<std::fs::File>, current_path: &Path, rotated_path: &Path) -> std::io::Result<()> {
+    let mut file = file.lock().unwrap();
+    file.flush()?;
+    std::fs::rename(current_path, rotated_path)?;
+    *file = OpenOptions::new().create(true).append(true).open(current_path)?;
+    Ok(())
+}
+
+/// Rotated log files are named by the UTC instant they were closed, so
+/// they sort chronologically alongside `current.log` by name alone.
+pub fn rotated_file_name(now: chrono::DateTime<Utc>) -> String {
+    format!("audit-{}.log", now.format("%Y%m%dT%H%M%SZ"))
+}
+
+/// Same naming scheme as `rotated_file_name`, prefixed so a rotated
+/// security log is still obviously distinct from a rotated main-log
+/// file in a directory listing.
+pub fn security_rotated_file_name(now: chrono::DateTime<Utc>) -> String {
+    format!("security-{}.log", now.format("%Y%m%dT%H%M%SZ"))
+}
+
+/// Reads the last line of an existing log file to recover the hash
+/// chain's tip across restarts. Returns `None` for a missing or empty
+/// file, in which case the chain starts fresh from `GENESIS_HASH`.
+fn last_entry_hash(log_path: &Path) -> Option<String> {
+    let file = std::fs::File::open(log_path).ok()?;
+    let lines: Vec<String> = BufReader::new(file).lines().map_while(Result::ok).collect();
+    let last_line = lines.iter().rev().find(|l| !l.trim().is_empty())?;
+    let last_line = super::encryption::decrypt_if_sealed(last_line);
+    let entry: LogEntry = serde_json::from_str(&last_line).ok()?;
+    Some(entry.entry_hash)
+}
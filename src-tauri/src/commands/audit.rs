@@ -0,0 +1,295 @@
+//! Tauri commands over the audit log.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tauri::{Emitter, State, Window};
+
+use crate::audit::alerts::{AlertRule, TriggeredAlert};
+use crate::audit::archive::{archive_logs, open_archive, ArchiveSummary};
+use crate::audit::compression::compress_log_file;
+use crate::audit::crash::{CrashReport, CrashReports};
+use crate::audit::entry::{LogEntry, LogLevel};
+use crate::audit::export::{export_logs, ExportFormat, ExportSummary};
+use crate::audit::index::{get_audit_entries_page, get_audit_entry_by_id, search_logs_indexed, AuditEntryPage};
+use crate::audit::integrity::{verify_log_integrity, IntegrityReport};
+use crate::audit::metrics::AppMetrics;
+use crate::audit::query::parse_query;
+use crate::audit::reconcile::ReconciliationReport;
+use crate::audit::sampling::SamplingRule;
+use crate::audit::search::{search_logs, LogSearchFilter};
+use crate::audit::settings::AuditSettings;
+use crate::audit::signing;
+use crate::audit::stats::{get_log_statistics, rotated_file_inventory, LogStatistics};
+use crate::audit::writer::LogWriter;
+
+/// `current.log` and `security.log` first, then rotated files in reverse
+/// name order — every `security-*` file before every `audit-*` file,
+/// each group newest-first. `search_logs`/`export_logs` scan in this
+/// order and stop at the limit, so a small limit doesn't need to touch
+/// the oldest history.
+fn all_log_files(writer: &LogWriter) -> Vec<PathBuf> {
+    let mut files = vec![writer.current_path(), writer.security_path()];
+    let mut rotated: Vec<PathBuf> =
+        rotated_file_inventory(writer.log_dir()).into_iter().map(|info| writer.log_dir().join(info.file_name)).collect();
+    rotated.reverse();
+    files.extend(rotated);
+    files
+}
+
+/// Indexed lookup when available; falls back to scanning the files —
+/// the index currently only mirrors the main current file, so the
+/// indexed path misses rotated history and every `security.log` entry
+/// (`Security` and `Critical` levels are never mirrored).
+fn run_search(writer: &LogWriter, filter: &LogSearchFilter) -> Vec<LogEntry> {
+    if let Some(index) = writer.index() {
+        match search_logs_indexed(index, &writer.current_path(), filter) {
+            Ok(entries) => return entries,
+            Err(err) => tracing::warn!(%err, "audit index query failed, falling back to file scan"),
+        }
+    }
+    search_logs(&all_log_files(writer), filter)
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn search_logs_command(
+    writer: State<'_, Arc<LogWriter>>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    action_contains: Option<String>,
+    level: Option<LogLevel>,
+    user: Option<String>,
+    limit: usize,
+) -> Result<Vec<LogEntry>, String> {
+    let filter = LogSearchFilter { since, until, action_contains, level, user, limit };
+    Ok(run_search(&writer, &filter))
+}
+
+/// Parses a small query expression (`level:security AND
+/// action:*modification* AND ts>2024-01-01`) into a `LogSearchFilter`
+/// and runs it the same way `search_logs_command` does.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn query_logs_command(writer: State<'_, Arc<LogWriter>>, expr: String, limit: usize) -> Result<Vec<LogEntry>, String> {
+    let mut filter = parse_query(&expr).map_err(|e| e.to_string())?;
+    filter.limit = limit;
+    Ok(run_search(&writer, &filter))
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn export_logs_command(
+    window: Window,
+    writer: State<'_, Arc<LogWriter>>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    action_contains: Option<String>,
+    level: Option<LogLevel>,
+    user: Option<String>,
+    format: ExportFormat,
+    export_path: PathBuf,
+) -> Result<ExportSummary, String> {
+    let filter = LogSearchFilter { since, until, action_contains, level, user, limit: 0 };
+    export_logs(&all_log_files(&writer), &filter, format, &export_path, |count| {
+        let _ = window.emit("audit-export-progress", count);
+    })
+    .map_err(|e| e.to_string())
+}
+
+/// Unlike `export_logs_command`, the result is encrypted for handing
+/// off site rather than read locally — see `audit::archive`.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn archive_logs_command(
+    writer: State<'_, Arc<LogWriter>>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    action_contains: Option<String>,
+    level: Option<LogLevel>,
+    user: Option<String>,
+    passphrase: String,
+    archive_path: PathBuf,
+) -> Result<ArchiveSummary, String> {
+    let filter = LogSearchFilter { since, until, action_contains, level, user, limit: 0 };
+    archive_logs(&all_log_files(&writer), &filter, &passphrase, &archive_path).map_err(|e| e.to_string())
+}
+
+/// Decrypts an archive produced by `archive_logs_command`, returning
+/// its NDJSON contents as a string for the caller to parse or re-import.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn open_archive_command(archive_path: PathBuf, passphrase: String) -> Result<String, String> {
+    let bytes = open_archive(&archive_path, &passphrase).map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_log_statistics_command(writer: State<'_, Arc<LogWriter>>) -> LogStatistics {
+    get_log_statistics(writer.stats(), writer.log_dir(), writer.last_reconciliation())
+}
+
+/// Runs the same index/file diff the background reconciliation job does
+/// (see `audit::reconcile::spawn`), for an admin who wants the result
+/// immediately rather than waiting for the next sweep.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn reconcile_audit_index_command(writer: State<'_, Arc<LogWriter>>) -> Result<ReconciliationReport, String> {
+    writer.run_reconciliation().ok_or_else(|| "audit index unavailable".to_string())
+}
+
+/// Dashboard gauges derived from the audit stream rather than a
+/// separate metrics instrumentation pass — see `audit::metrics`.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_app_metrics_command(writer: State<'_, Arc<LogWriter>>) -> AppMetrics {
+    writer.metrics()
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn verify_log_integrity_command(writer: State<'_, Arc<LogWriter>>) -> Result<IntegrityReport, String> {
+    verify_log_integrity(&writer.current_path()).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn sign_log_file_command(log_path: PathBuf) -> Result<(), String> {
+    signing::sign_file(&log_path).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn verify_log_signature_command(log_path: PathBuf) -> Result<(), String> {
+    signing::verify_file(&log_path).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn audit_signing_public_key_command() -> Result<String, String> {
+    signing::verifying_key_hex().map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn compress_log_file_command(log_path: PathBuf) -> Result<PathBuf, String> {
+    compress_log_file(&log_path).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_audit_settings_command(writer: State<'_, Arc<LogWriter>>) -> AuditSettings {
+    writer.settings()
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn set_audit_settings_command(writer: State<'_, Arc<LogWriter>>, settings: AuditSettings) {
+    writer.set_settings(settings);
+}
+
+/// Rotates unconditionally, unlike the background task which only
+/// rotates when `should_rotate` says to. Unlike the background task,
+/// this also doesn't split the result by level — that only happens on
+/// the periodic sweep, so a manually-rotated file is pruned under the
+/// mixed-file retention fallback.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn rotate_now_command(writer: State<'_, Arc<LogWriter>>) -> Result<PathBuf, String> {
+    writer.rotate_now().map_err(|e| e.to_string())
+}
+
+/// Manually drains the remote log shipper's spool, for an admin who
+/// doesn't want to wait for the periodic retry sweep after fixing
+/// connectivity. Returns `None` if no shipper is configured.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn drain_shipper_spool_command(writer: State<'_, Arc<LogWriter>>) -> Result<Option<usize>, String> {
+    match writer.shipper() {
+        Some(shipper) => shipper.drain_spool().await.map(Some).map_err(|e| e.to_string()),
+        None => Ok(None),
+    }
+}
+
+/// Backs the paginated audit browser: pass back `next_cursor` from the
+/// previous page as `page_cursor` to keep paging, `None` for the first
+/// page. Needs an index — without one there's no cheap way to page
+/// through a potentially multi-gigabyte file, so this returns an error
+/// rather than silently falling back to a full scan per page.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_audit_entries_command(
+    writer: State<'_, Arc<LogWriter>>,
+    page_cursor: Option<i64>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    action_contains: Option<String>,
+    level: Option<LogLevel>,
+    user: Option<String>,
+    page_size: usize,
+) -> Result<AuditEntryPage, String> {
+    let index = writer.index().ok_or("audit index unavailable")?;
+    let filter = LogSearchFilter { since, until, action_contains, level, user, limit: 0 };
+    get_audit_entries_page(index, &writer.current_path(), &filter, page_cursor, page_size).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_audit_entry_command(writer: State<'_, Arc<LogWriter>>, id: i64) -> Result<Option<LogEntry>, String> {
+    let index = writer.index().ok_or("audit index unavailable")?;
+    get_audit_entry_by_id(index, &writer.current_path(), id).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_alert_rules_command(writer: State<'_, Arc<LogWriter>>) -> Result<Vec<AlertRule>, String> {
+    Ok(writer.alerts().ok_or("alert engine unavailable")?.rules())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn set_alert_rules_command(writer: State<'_, Arc<LogWriter>>, rules: Vec<AlertRule>) -> Result<(), String> {
+    writer.alerts().ok_or("alert engine unavailable")?.set_rules(rules);
+    Ok(())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn list_triggered_alerts_command(writer: State<'_, Arc<LogWriter>>) -> Result<Vec<TriggeredAlert>, String> {
+    Ok(writer.alerts().ok_or("alert engine unavailable")?.triggered_alerts())
+}
+
+/// Panics and unclean shutdowns from the previous run, recovered into
+/// Critical audit entries at startup by `audit::crash::recover_crash_reports`
+/// — this just serves the copy held onto for the session.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_crash_reports_command(reports: State<'_, CrashReports>) -> Vec<CrashReport> {
+    reports.list()
+}
+
+/// Whether `writer` is sealing newly-appended lines at rest — see
+/// `LogWriter::with_encryption`. There's no toggle command: turning
+/// this on mid-session would leave the already-open file handle
+/// unencrypted for the rest of the run, so it's an at-construction
+/// choice rather than a runtime setting.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn is_audit_encryption_enabled_command(writer: State<'_, Arc<LogWriter>>) -> bool {
+    writer.encrypt_at_rest()
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_sampling_rules_command(writer: State<'_, Arc<LogWriter>>) -> Result<Vec<SamplingRule>, String> {
+    Ok(writer.sampler().ok_or("sampling not enabled")?.rules())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn set_sampling_rules_command(writer: State<'_, Arc<LogWriter>>, rules: Vec<SamplingRule>) -> Result<(), String> {
+    writer.sampler().ok_or("sampling not enabled")?.set_rules(rules);
+    Ok(())
+}
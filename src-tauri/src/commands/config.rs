@@ -0,0 +1,354 @@
+//! Tauri commands over the live, hot-reloadable app config.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tauri::State;
+use uuid::Uuid;
+
+use crate::audit::entry::LogEntry;
+use crate::audit::event::log_security;
+use crate::audit::search::{search_logs, LogSearchFilter};
+use crate::audit::writer::LogWriter;
+use crate::config::audit_trail::{record_config_change, CONFIG_CHANGE_ACTION};
+use crate::config::hot_reload::ConfigState;
+use crate::config::model_endpoints::{self, ModelEndpoint, ModelEndpointError};
+use crate::config::persistence;
+use crate::config::transfer::{self, ImportPreview, TransferError};
+use crate::config::validation::{validate_active_model_endpoint, validate_agent_settings, FieldViolation};
+use crate::config::{export_config, load_config, AppConfig};
+use crate::security::admin_auth::AdminAuth;
+use crate::security::events::RequestContext;
+use crate::security::vault::Vault;
+use crate::settings::AgentSettings;
+
+fn check_admin(admin_auth: &State<'_, Arc<AdminAuth>>, session_id: &str, admin_code: &str) -> Result<(), String> {
+    admin_auth.verify_admin_code(session_id, admin_code).map_err(|e| e.to_string())
+}
+
+/// Serialized to the frontend as `{ kind, ... }` so the settings UI can
+/// distinguish "fix these fields" from "you're not allowed to do this"
+/// and, for `validation`, highlight exactly which fields are wrong
+/// rather than surfacing one generic message.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum UpdateSettingsError {
+    Unauthorized { message: String },
+    Validation { violations: Vec<FieldViolation> },
+    Io { message: String },
+}
+
+/// Same shape as `UpdateSettingsError`, for the model endpoint registry
+/// commands — kept as its own type since `Conflict` (name already taken,
+/// name not found) doesn't apply to agent settings.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ModelEndpointCommandError {
+    Unauthorized { message: String },
+    Validation { violations: Vec<FieldViolation> },
+    Conflict { message: String },
+    Io { message: String },
+}
+
+impl From<ModelEndpointError> for ModelEndpointCommandError {
+    fn from(err: ModelEndpointError) -> Self {
+        match err {
+            ModelEndpointError::NotFound(_) | ModelEndpointError::AlreadyExists(_) => {
+                ModelEndpointCommandError::Conflict { message: err.to_string() }
+            }
+            other => ModelEndpointCommandError::Io { message: other.to_string() },
+        }
+    }
+}
+
+/// Surfaced when reading back or validating an imported config file —
+/// kept distinct from `UpdateSettingsError` since there's no
+/// authorization check on a read-only preview.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ImportConfigError {
+    Unauthorized { message: String },
+    Validation { violations: Vec<FieldViolation> },
+    Io { message: String },
+}
+
+impl From<TransferError> for ImportConfigError {
+    fn from(err: TransferError) -> Self {
+        match err {
+            TransferError::Validation(violations) => ImportConfigError::Validation { violations },
+            other => ImportConfigError::Io { message: other.to_string() },
+        }
+    }
+}
+
+/// Rewrites only the `agent` section of the config file, leaving
+/// everything else (and the file's existing formatting of other
+/// sections) alone — this intentionally does not write out the fully
+/// merged config, since that would bake env var overrides into the file
+/// permanently instead of leaving them to keep winning on every load.
+fn persist_agent_settings(config_path: &Path, settings: &AgentSettings) -> std::io::Result<()> {
+    let mut value: serde_json::Value = std::fs::read_to_string(config_path)
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_else(|| serde_json::json!({}));
+    value["agent"] = serde_json::to_value(settings).unwrap_or_default();
+    persistence::save(config_path, &serde_json::to_string_pretty(&value)?)
+}
+
+/// Same approach as `persist_agent_settings`: rewrites only the
+/// `modelEndpoints`/`activeModelEndpoint` keys, leaving everything else
+/// (including any env var overrides) alone.
+fn persist_model_endpoints(
+    config_path: &Path,
+    endpoints: &[ModelEndpoint],
+    active: &Option<String>,
+) -> std::io::Result<()> {
+    let mut value: serde_json::Value = std::fs::read_to_string(config_path)
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_else(|| serde_json::json!({}));
+    value["modelEndpoints"] = serde_json::to_value(endpoints).unwrap_or_default();
+    value["activeModelEndpoint"] = serde_json::to_value(active).unwrap_or_default();
+    persistence::save(config_path, &serde_json::to_string_pretty(&value)?)
+}
+
+/// Current in-memory config, reflecting whatever the hot-reload watcher
+/// has applied since startup — see `config::hot_reload::watch`.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_config_command(state: State<'_, Arc<ConfigState>>) -> AppConfig {
+    state.current()
+}
+
+/// Serializes the live config for sharing (support requests, a backup
+/// the user downloads) — safe to send as-is since it only ever holds a
+/// vault entry *name* for secret-bearing values, never the value.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn export_config_command(state: State<'_, Arc<ConfigState>>) -> Result<String, String> {
+    export_config(&state.current()).map_err(|e| e.to_string())
+}
+
+/// Validates, persists to the config file, applies to the live config
+/// (read by `memory::search`'s hybrid ranking on every query, so this
+/// takes effect on the next one rather than needing a restart), and
+/// audits the change. Only `agent` exists as an updatable section in
+/// this tree today — there's no audio subsystem, and the database pool
+/// built in `run` isn't configured from `AppConfig`, so there's nothing
+/// to re-open or resize, despite the app's name.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn update_agent_settings_command(
+    state: State<'_, Arc<ConfigState>>,
+    writer: State<'_, Arc<LogWriter>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    settings: AgentSettings,
+) -> Result<AppConfig, UpdateSettingsError> {
+    check_admin(&admin_auth, &session_id, &admin_code).map_err(|message| UpdateSettingsError::Unauthorized { message })?;
+    validate_agent_settings(&settings).map_err(|violations| UpdateSettingsError::Validation { violations })?;
+
+    let before = state.current();
+    persist_agent_settings(state.config_path(), &settings)
+        .map_err(|err| UpdateSettingsError::Io { message: err.to_string() })?;
+    let config = load_config(state.config_path()).map_err(|err| UpdateSettingsError::Io { message: err.to_string() })?;
+    state.set_current(config.clone());
+
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    let _ = log_security(&writer, &ctx, "config.agent_settings_updated", "agent settings updated");
+    let _ = record_config_change(&writer, &ctx, &before, &config);
+
+    Ok(config)
+}
+
+/// History of applied config changes, each entry's metadata holding a
+/// JSON diff of before/after — see `config::audit_trail`.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_config_history_command(writer: State<'_, Arc<LogWriter>>, limit: usize) -> Vec<LogEntry> {
+    let filter = LogSearchFilter {
+        since: None,
+        until: None,
+        action_contains: Some(CONFIG_CHANGE_ACTION.to_string()),
+        level: None,
+        user: None,
+        limit,
+    };
+    // Config changes are logged at `Critical` and so live in security.log,
+    // which the index doesn't mirror — always scan the files.
+    search_logs(&[writer.current_path(), writer.security_path()], &filter)
+}
+
+/// Adds `endpoint` to the registry, persists, applies, and audits.
+/// Leaves `active_model_endpoint` untouched — adding an endpoint never
+/// switches to it on its own.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn add_model_endpoint_command(
+    state: State<'_, Arc<ConfigState>>,
+    writer: State<'_, Arc<LogWriter>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    endpoint: ModelEndpoint,
+) -> Result<AppConfig, ModelEndpointCommandError> {
+    check_admin(&admin_auth, &session_id, &admin_code).map_err(|message| ModelEndpointCommandError::Unauthorized { message })?;
+
+    let before = state.current();
+    let mut endpoints = before.model_endpoints.clone();
+    model_endpoints::add_endpoint(&mut endpoints, endpoint)?;
+
+    persist_model_endpoints(state.config_path(), &endpoints, &before.active_model_endpoint)
+        .map_err(|err| ModelEndpointCommandError::Io { message: err.to_string() })?;
+    let config = load_config(state.config_path()).map_err(|err| ModelEndpointCommandError::Io { message: err.to_string() })?;
+    state.set_current(config.clone());
+
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    let _ = log_security(&writer, &ctx, "config.model_endpoint_added", "model endpoint added");
+    let _ = record_config_change(&writer, &ctx, &before, &config);
+
+    Ok(config)
+}
+
+/// Removes the endpoint named `name`. Refuses to leave
+/// `active_model_endpoint` dangling — if it's the active one, the
+/// frontend needs to pick a new active endpoint (or clear it) first.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn remove_model_endpoint_command(
+    state: State<'_, Arc<ConfigState>>,
+    writer: State<'_, Arc<LogWriter>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    name: String,
+) -> Result<AppConfig, ModelEndpointCommandError> {
+    check_admin(&admin_auth, &session_id, &admin_code).map_err(|message| ModelEndpointCommandError::Unauthorized { message })?;
+
+    let before = state.current();
+    if before.active_model_endpoint.as_deref() == Some(name.as_str()) {
+        return Err(ModelEndpointCommandError::Conflict {
+            message: format!("'{name}' is the active model endpoint; switch away from it before removing it"),
+        });
+    }
+
+    let mut endpoints = before.model_endpoints.clone();
+    model_endpoints::remove_endpoint(&mut endpoints, &name)?;
+
+    persist_model_endpoints(state.config_path(), &endpoints, &before.active_model_endpoint)
+        .map_err(|err| ModelEndpointCommandError::Io { message: err.to_string() })?;
+    let config = load_config(state.config_path()).map_err(|err| ModelEndpointCommandError::Io { message: err.to_string() })?;
+    state.set_current(config.clone());
+
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    let _ = log_security(&writer, &ctx, "config.model_endpoint_removed", "model endpoint removed");
+    let _ = record_config_change(&writer, &ctx, &before, &config);
+
+    Ok(config)
+}
+
+/// Changes which endpoint is active. Validated against the current
+/// registry before anything is written, so a typo'd name never makes it
+/// into the config file.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn set_active_model_endpoint_command(
+    state: State<'_, Arc<ConfigState>>,
+    writer: State<'_, Arc<LogWriter>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    name: Option<String>,
+) -> Result<AppConfig, ModelEndpointCommandError> {
+    check_admin(&admin_auth, &session_id, &admin_code).map_err(|message| ModelEndpointCommandError::Unauthorized { message })?;
+
+    let before = state.current();
+    validate_active_model_endpoint(&before.model_endpoints, name.as_deref())
+        .map_err(|violations| ModelEndpointCommandError::Validation { violations })?;
+
+    persist_model_endpoints(state.config_path(), &before.model_endpoints, &name)
+        .map_err(|err| ModelEndpointCommandError::Io { message: err.to_string() })?;
+    let config = load_config(state.config_path()).map_err(|err| ModelEndpointCommandError::Io { message: err.to_string() })?;
+    state.set_current(config.clone());
+
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    let _ = log_security(&writer, &ctx, "config.active_model_endpoint_changed", "active model endpoint changed");
+    let _ = record_config_change(&writer, &ctx, &before, &config);
+
+    Ok(config)
+}
+
+/// Health-checks `name` against the live registry without touching the
+/// config file — a dry run before committing to `set_active_model_endpoint_command`.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn test_model_endpoint_command(
+    state: State<'_, Arc<ConfigState>>,
+    vault: State<'_, Mutex<Vault>>,
+    name: String,
+) -> Result<(), String> {
+    let config = state.current();
+    let endpoint = config
+        .model_endpoints
+        .iter()
+        .find(|endpoint| endpoint.name == name)
+        .ok_or_else(|| ModelEndpointError::NotFound(name.clone()).to_string())?
+        .clone();
+    let auth_token = {
+        let vault = vault.lock().unwrap();
+        model_endpoints::resolve_auth_token(&endpoint, Some(&vault)).map_err(|e| e.to_string())?
+    };
+    model_endpoints::test_endpoint(&endpoint, auth_token.as_deref()).await.map_err(|e| e.to_string())
+}
+
+/// Writes the live config to `path` for the user to back up or copy onto
+/// another machine — see `config::transfer` for what gets templated.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn export_config_to_file_command(state: State<'_, Arc<ConfigState>>, path: String) -> Result<(), String> {
+    transfer::export_to_file(&state.current(), std::path::Path::new(&path)).map_err(|e| e.to_string())
+}
+
+/// Reads and validates the config at `path`, returning it alongside a
+/// diff against the live config — nothing is applied here. The frontend
+/// shows the diff and, if the user confirms, calls
+/// `apply_imported_config_command` with the previewed config.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn preview_config_import_command(state: State<'_, Arc<ConfigState>>, path: String) -> Result<ImportPreview, ImportConfigError> {
+    Ok(transfer::import_from_file(std::path::Path::new(&path), &state.current())?)
+}
+
+/// Applies a config the user already confirmed via
+/// `preview_config_import_command` — re-validated here too, since the
+/// file on disk (or the live config it's diffed against) could have
+/// changed in between the two calls.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn apply_imported_config_command(
+    state: State<'_, Arc<ConfigState>>,
+    writer: State<'_, Arc<LogWriter>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    path: String,
+) -> Result<AppConfig, ImportConfigError> {
+    check_admin(&admin_auth, &session_id, &admin_code)
+        .map_err(|message| ImportConfigError::Unauthorized { message })?;
+
+    let before = state.current();
+    let preview = transfer::import_from_file(std::path::Path::new(&path), &before)?;
+
+    let contents = serde_json::to_string_pretty(&preview.config).map_err(|err| ImportConfigError::Io { message: err.to_string() })?;
+    persistence::save(state.config_path(), &contents).map_err(|err| ImportConfigError::Io { message: err.to_string() })?;
+    let config = load_config(state.config_path()).map_err(|err| ImportConfigError::Io { message: err.to_string() })?;
+    state.set_current(config.clone());
+
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    let _ = log_security(&writer, &ctx, "config.imported", "configuration imported from file");
+    let _ = record_config_change(&writer, &ctx, &before, &config);
+
+    Ok(config)
+}
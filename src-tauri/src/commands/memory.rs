@@ -0,0 +1,65 @@
+//! Tauri commands exposing memory statistics and management to the
+//! frontend.
+
+use serde::Serialize;
+use sqlx::PgPool;
+use tauri::State;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize)]
+pub struct MemoryStats {
+    pub total_memories: i64,
+    pub by_type: Vec<(String, i64)>,
+    pub expired_pending_purge: i64,
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn get_memory_stats(pool: State<'_, PgPool>) -> Result<MemoryStats, String> {
+    let (total_memories,): (i64,) = sqlx::query_as("SELECT count(*) FROM memories WHERE deleted_at IS NULL")
+        .fetch_one(pool.inner())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let by_type: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT memory_type, count(*) FROM memories WHERE deleted_at IS NULL GROUP BY memory_type ORDER BY count(*) DESC",
+    )
+    .fetch_all(pool.inner())
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let (expired_pending_purge,): (i64,) = sqlx::query_as(
+        "SELECT count(*) FROM memories WHERE expires_at IS NOT NULL AND expires_at <= now()",
+    )
+    .fetch_one(pool.inner())
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(MemoryStats { total_memories, by_type, expired_pending_purge })
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn delete_memory(pool: State<'_, PgPool>, id: Uuid) -> Result<(), String> {
+    crate::memory::trash::soft_delete(pool.inner(), id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn restore_memory(pool: State<'_, PgPool>, id: Uuid) -> Result<(), String> {
+    crate::memory::trash::restore(pool.inner(), id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn list_trashed_memories(
+    pool: State<'_, PgPool>,
+) -> Result<Vec<crate::memory::trash::TrashedMemory>, String> {
+    crate::memory::trash::list_trash(pool.inner())
+        .await
+        .map_err(|e| e.to_string())
+}
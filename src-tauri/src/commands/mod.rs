@@ -0,0 +1,5 @@
+pub mod audit;
+pub mod config;
+pub mod memory;
+pub mod security;
+pub mod system;
@@ -0,0 +1,455 @@
+//! Tauri commands for the secrets vault and the security subsystem. The
+//! vault commands, and every other command that takes an `admin_code`,
+//! are gated on the admin code so a compromised frontend can't read or
+//! change secrets without the human in the loop; the commands without
+//! that parameter run ungated.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use tauri::State;
+
+use uuid::Uuid;
+
+use crate::security::admin_auth::AdminAuth;
+use crate::security::events::RequestContext;
+use crate::security::lockdown::LockdownState;
+use crate::security::manager::SecurityManager;
+use crate::security::modifications::{Comment, ModificationRegistry, ModificationRequest, ModificationStatus};
+use crate::security::rbac::{Permission, Role, User, UserStore};
+use crate::security::report::SecurityReport;
+use crate::security::vault::Vault;
+
+const EXPECTED_SECRETS: &[&str] = &["admin_code_hash"];
+
+fn check_admin(admin_auth: &State<'_, Arc<AdminAuth>>, session_id: &str, admin_code: &str) -> Result<(), String> {
+    admin_auth
+        .verify_admin_code(session_id, admin_code)
+        .map_err(|e| e.to_string())
+}
+
+/// Checks that `acting_user_id` holds `permission`, on top of whatever
+/// admin-code check already ran. The admin code proves "this caller is
+/// a trusted operator"; this proves "this operator is allowed to do
+/// this specific thing".
+fn check_permission(users: &State<'_, UserStore>, acting_user_id: &str, permission: Permission) -> Result<(), String> {
+    if users.has_permission(acting_user_id, permission) {
+        Ok(())
+    } else {
+        Err(format!("user '{acting_user_id}' lacks permission {permission:?}"))
+    }
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn vault_set(
+    vault: State<'_, Mutex<Vault>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    name: String,
+    value: String,
+) -> Result<(), String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    vault.lock().unwrap().set(&name, &value).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn vault_get(
+    vault: State<'_, Mutex<Vault>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    name: String,
+) -> Result<String, String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    vault.lock().unwrap().get(&name).map(str::to_string).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn vault_list(
+    vault: State<'_, Mutex<Vault>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+) -> Result<Vec<String>, String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    Ok(vault.lock().unwrap().list().into_iter().map(str::to_string).collect())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn vault_delete(
+    vault: State<'_, Mutex<Vault>>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    name: String,
+) -> Result<(), String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    vault.lock().unwrap().delete(&name).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_security_report(
+    manager: State<'_, SecurityManager>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+) -> Result<SecurityReport, String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    Ok(manager.security_report(EXPECTED_SECRETS))
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn reload_policy(
+    manager: State<'_, SecurityManager>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    policy_path: PathBuf,
+) -> Result<(), String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    manager.load_policy(&policy_path, &ctx).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn add_modification_comment(
+    registry: State<'_, ModificationRegistry>,
+    author: String,
+    request_id: Uuid,
+    text: String,
+) -> Result<Comment, String> {
+    registry.add_comment(request_id, &author, &text).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn list_modification_comments(
+    registry: State<'_, ModificationRegistry>,
+    request_id: Uuid,
+) -> Result<Vec<Comment>, String> {
+    registry.list_comments(request_id).map_err(|e| e.to_string())
+}
+
+/// Issues a session token bound to this machine's fingerprint. No
+/// admin-code check is made here, and the privileged commands take the
+/// admin code, not a token, so a token doesn't stand in for it.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn issue_session_command(manager: State<'_, SecurityManager>) -> Result<String, String> {
+    let ctx = RequestContext::system();
+    Ok(manager.issue_session(&ctx))
+}
+
+/// Checks whether `token` is still a live session issued to this
+/// machine. Callers that get back `false` should fall back to asking
+/// for the admin code again rather than assuming the session merely
+/// expired.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn validate_session_command(manager: State<'_, SecurityManager>, token: String) -> Result<bool, String> {
+    let ctx = RequestContext::system();
+    Ok(manager.validate_session(&token, &ctx))
+}
+
+/// Transitions the emergency lockdown state. Admin-gated in both
+/// directions — freezing the system is as consequential as unfreezing
+/// it. Lifting a `Frozen` lockdown also goes through the biometric gate
+/// if `SecuritySettings::require_biometric_for` lists it, but the gate
+/// is `NoopBiometricGate` (see `security::biometric`), so that check
+/// passes without prompting anyone.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn transition_lockdown_command(
+    manager: State<'_, SecurityManager>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    to: LockdownState,
+) -> Result<(), String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    manager.transition_lockdown(to, &ctx).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn lockdown_state_command(manager: State<'_, SecurityManager>) -> Result<LockdownState, String> {
+    Ok(manager.lockdown_state())
+}
+
+/// Opens a modification request for review: checks it against the
+/// current modification-type allowlist and rules before it's ever
+/// eligible for approval, so a disallowed or rule-violating request
+/// never reaches an approver's queue in the first place.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn propose_modification(
+    registry: State<'_, ModificationRegistry>,
+    manager: State<'_, SecurityManager>,
+    file_path: PathBuf,
+    diff: String,
+    rationale: String,
+) -> Result<ModificationRequest, String> {
+    let request = ModificationRequest {
+        id: Uuid::new_v4(),
+        file_path,
+        diff,
+        rationale,
+        status: ModificationStatus::Pending,
+        findings: Vec::new(),
+        comments: Vec::new(),
+        simulation: None,
+    };
+    let ctx = RequestContext::system();
+    manager.check_modification_type(&request, &ctx).map_err(|e| e.to_string())?;
+    let violations = manager.evaluate_rules(&request, &ctx);
+    if !violations.is_empty() {
+        return Err(format!("rule violations: {violations:?}"));
+    }
+    let changed_lines = request.diff.lines().count();
+    let risk = manager.current_policy_risk(changed_lines).unwrap_or("low");
+    manager.record_modification_request(risk);
+    registry.insert(request.clone());
+    Ok(request)
+}
+
+/// Checks the anomaly signals (failed-attempt bursts, out-of-hours
+/// activity, repeated high-risk requests) accumulated since the app
+/// started and freezes the system if any tripped. Meant to be polled
+/// by the frontend after a failed admin-code attempt or on an interval
+/// — there's no background timer driving this on its own.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn check_anomaly_lockdown_command(manager: State<'_, SecurityManager>) -> Result<Vec<String>, String> {
+    let ctx = RequestContext::system();
+    let notifier = manager.webhook_notifier().unwrap_or_else(|| crate::security::notifications::WebhookNotifier::new(Vec::new(), String::new()));
+    let signals = manager.enforce_anomaly_lockdown(&notifier, &ctx).await;
+    Ok(signals.into_iter().map(|s| format!("{s:?}")).collect())
+}
+
+/// Opens the approval window for a proposed request, requiring `quorum`
+/// distinct approvers before `approve_modification_command` reports it
+/// as approved. Admin-gated like every other action that changes what a
+/// pending request needs before it can be applied.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn open_approval_command(
+    registry: State<'_, ModificationRegistry>,
+    manager: State<'_, SecurityManager>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    request_id: Uuid,
+    quorum: usize,
+) -> Result<(), String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    manager.open_approval(request_id, quorum, &ctx);
+    if let Some(notifier) = manager.webhook_notifier() {
+        let rationale = registry.get(request_id).map(|r| r.rationale).unwrap_or_default();
+        notifier.notify(&crate::security::notifications::ApprovalEvent::PendingApproval { request_id, rationale }).await;
+    }
+    Ok(())
+}
+
+/// Records one approval from `approver`. Once quorum is reached the
+/// request's status flips to `Approved`, which is what
+/// `apply_modification_command` checks before it will touch the
+/// working tree. Requires the acting user to hold `ApproveModification`
+/// on top of the admin code, so an operator who's merely authenticated
+/// can't approve changes they weren't granted the role to approve.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub async fn approve_modification_command(
+    registry: State<'_, ModificationRegistry>,
+    manager: State<'_, SecurityManager>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    users: State<'_, UserStore>,
+    session_id: String,
+    admin_code: String,
+    acting_user_id: String,
+    request_id: Uuid,
+    approver: String,
+) -> Result<bool, String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    check_permission(&users, &acting_user_id, Permission::ApproveModification)?;
+    let ctx = RequestContext { session_id: Some(session_id), user_id: Some(acting_user_id), request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    let quorum_reached = manager.approve_modification(request_id, &approver, &ctx).map_err(|e| e.to_string())?;
+    if quorum_reached {
+        registry.set_status(request_id, ModificationStatus::Approved).map_err(|e| e.to_string())?;
+        if let Some(notifier) = manager.webhook_notifier() {
+            notifier.notify(&crate::security::notifications::ApprovalEvent::QuorumReached { request_id }).await;
+        }
+    }
+    Ok(quorum_reached)
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn reject_modification_command(
+    registry: State<'_, ModificationRegistry>,
+    manager: State<'_, SecurityManager>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    request_id: Uuid,
+) -> Result<(), String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    let ctx = RequestContext { session_id: Some(session_id), user_id: None, request_id: Uuid::new_v4(), origin: "tauri_command".to_string() };
+    manager.reject_modification(request_id, &ctx);
+    registry.set_status(request_id, ModificationStatus::Rejected).map_err(|e| e.to_string())
+}
+
+/// Runs the `syn`-based static analyzer against a proposed request and
+/// stores the findings on it, so an approver reviewing the request sees
+/// them without having to trigger a separate simulation run first.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn analyze_modification_command(
+    registry: State<'_, ModificationRegistry>,
+    manager: State<'_, SecurityManager>,
+    request_id: Uuid,
+) -> Result<Vec<crate::security::static_analysis::StaticFinding>, String> {
+    let mut request = registry.get(request_id).map_err(|e| e.to_string())?;
+    let ctx = RequestContext::system();
+    let findings = manager.analyze_modification(&mut request, &ctx).map_err(|e| e.to_string())?.to_vec();
+    registry.set_findings(request_id, findings.clone()).map_err(|e| e.to_string())?;
+    Ok(findings)
+}
+
+/// Applies an approved modification on a dedicated `modification/<id>`
+/// branch (see `git_workflow::apply_on_branch`) and marks the request
+/// `Applied`. Refuses anything not already `Approved` so quorum can't
+/// be bypassed by calling this directly. Requires the acting user to
+/// hold `ApplyModification` on top of the admin code — approving and
+/// applying are deliberately separate permissions. Refused while the
+/// lockdown state is anything but `Normal`.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn apply_modification_command(
+    registry: State<'_, ModificationRegistry>,
+    manager: State<'_, SecurityManager>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    users: State<'_, UserStore>,
+    session_id: String,
+    admin_code: String,
+    acting_user_id: String,
+    repo_path: PathBuf,
+    request_id: Uuid,
+) -> Result<(), String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    check_permission(&users, &acting_user_id, Permission::ApplyModification)?;
+    if manager.lockdown_state() != LockdownState::Normal {
+        return Err("modifications can't be applied while the system is in lockdown".to_string());
+    }
+    let request = registry.get(request_id).map_err(|e| e.to_string())?;
+    if request.status != ModificationStatus::Approved {
+        return Err("modification is not approved".to_string());
+    }
+    crate::security::git_workflow::apply_on_branch(&repo_path, &request).map_err(|e| e.to_string())?;
+    registry.set_status(request_id, ModificationStatus::Applied).map_err(|e| e.to_string())
+}
+
+/// Creates an RBAC user with no roles yet (see `assign_role_command`).
+/// Gated on `ManageUsers` like role assignment, except for the very
+/// first user — nobody can hold `ManageUsers` before one exists, so the
+/// check is skipped while the store has no manager at all.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn create_user_command(
+    users: State<'_, UserStore>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    acting_user_id: String,
+    id: String,
+    display_name: String,
+) -> Result<User, String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    if users.has_any_manager() {
+        check_permission(&users, &acting_user_id, Permission::ManageUsers)?;
+    }
+    Ok(users.create(&id, &display_name))
+}
+
+/// Grants `role` to an existing user. Same bootstrap exception as
+/// `create_user_command`: the first `Owner` assignment doesn't require
+/// `ManageUsers` yet, since nothing can hold it before then.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn assign_role_command(
+    users: State<'_, UserStore>,
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+    acting_user_id: String,
+    id: String,
+    role: Role,
+) -> Result<User, String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    if users.has_any_manager() {
+        check_permission(&users, &acting_user_id, Permission::ManageUsers)?;
+    }
+    users.assign_role(&id, role).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn list_users_command(users: State<'_, UserStore>) -> Result<Vec<User>, String> {
+    Ok(users.list())
+}
+
+const TOTP_ACCOUNT_NAME: &str = "admin";
+
+/// Generates a new TOTP secret, stores it in the keyring, and returns
+/// the `otpauth://` provisioning URI for the admin to scan into an
+/// authenticator app. Calling this again replaces any secret from a
+/// previous enrollment.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn enroll_totp_command(
+    admin_auth: State<'_, Arc<AdminAuth>>,
+    session_id: String,
+    admin_code: String,
+) -> Result<String, String> {
+    check_admin(&admin_auth, &session_id, &admin_code)?;
+    let secret = crate::security::totp::generate_secret();
+    crate::security::keyring_store::set_secret("totp_secret", &secret).map_err(|e| e.to_string())?;
+    crate::security::totp::provisioning_uri(&secret, TOTP_ACCOUNT_NAME).map_err(|e| e.to_string())
+}
+
+/// Verifies a 6-digit TOTP code against the secret enrolled via
+/// `enroll_totp_command`. Layered on top of (not instead of) the admin
+/// code — callers should already have called `check_admin` equivalent
+/// verification before asking for this.
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn verify_totp_command(code: String) -> Result<(), String> {
+    let secret = crate::security::keyring_store::get_secret("totp_secret")
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "no TOTP secret enrolled".to_string())?;
+    crate::security::totp::verify_code(&secret, TOTP_ACCOUNT_NAME, &code).map_err(|e| e.to_string())
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn simulate_modification(
+    registry: State<'_, ModificationRegistry>,
+    manager: State<'_, SecurityManager>,
+    repo_path: PathBuf,
+    request_id: Uuid,
+) -> Result<crate::security::simulation::SimulationResult, String> {
+    let request = registry.get(request_id).map_err(|e| e.to_string())?;
+    let ctx = RequestContext::system();
+    let result = manager.simulate_modification(&repo_path, &request, &ctx).map_err(|e| e.to_string())?;
+    registry.set_simulation(request_id, result.clone()).map_err(|e| e.to_string())?;
+    Ok(result)
+}
@@ -0,0 +1,18 @@
+//! Tauri commands for app-wide runtime controls that don't belong to
+//! any single subsystem.
+
+use tauri::State;
+
+use crate::tracing_setup::ReloadHandle;
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn get_log_level_command(handle: State<'_, ReloadHandle>) -> String {
+    crate::tracing_setup::current_level(&handle)
+}
+
+#[tracing::instrument(skip_all)]
+#[tauri::command]
+pub fn set_log_level_command(handle: State<'_, ReloadHandle>, directive: String) -> Result<(), String> {
+    crate::tracing_setup::set_level(&handle, &directive)
+}
@@ -0,0 +1,69 @@
+//! Every applied config change is recorded through the audit `LogWriter` as a
+//! JSON diff of before/after, not just "a change happened" — so
+//! `get_config_history` can answer when a particular setting drifted
+//! and to what, not only that something did.
+
+use serde_json::Value;
+
+use crate::audit::entry::LogLevel;
+use crate::audit::event::AuditEventExt;
+use crate::audit::writer::LogWriter;
+use crate::security::events::RequestContext;
+
+use super::AppConfig;
+
+pub const CONFIG_CHANGE_ACTION: &str = "config.changed";
+
+/// Dotted paths (matching the JSON field names) whose value is never
+/// written to the audit trail, only whether it changed — a secret
+/// *name* reference is still one fewer thing for this log to leak if
+/// the field it points at ever stops being just a name.
+pub(crate) const REDACTED_FIELDS: &[&str] = &["databaseUrlSecret"];
+
+/// No-ops if `before` and `after` are identical, so a no-op "update"
+/// call doesn't pollute the history with an empty diff.
+pub fn record_config_change(
+    writer: &LogWriter,
+    ctx: &RequestContext,
+    before: &AppConfig,
+    after: &AppConfig,
+) -> std::io::Result<()> {
+    let diff = diff_config(before, after);
+    if diff.is_empty() {
+        return Ok(());
+    }
+    writer
+        .event(ctx, CONFIG_CHANGE_ACTION)
+        .level(LogLevel::Critical)
+        .message("configuration changed")
+        .meta(Value::Object(diff))
+        .write()
+}
+
+fn diff_config(before: &AppConfig, after: &AppConfig) -> serde_json::Map<String, Value> {
+    let before = serde_json::to_value(before).unwrap_or_default();
+    let after = serde_json::to_value(after).unwrap_or_default();
+    let mut diff = serde_json::Map::new();
+    collect_diff("", &before, &after, &mut diff);
+    diff
+}
+
+fn collect_diff(prefix: &str, before: &Value, after: &Value, out: &mut serde_json::Map<String, Value>) {
+    if let (Value::Object(b), Value::Object(a)) = (before, after) {
+        for (key, a_val) in a {
+            let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+            let b_val = b.get(key).cloned().unwrap_or(Value::Null);
+            collect_diff(&path, &b_val, a_val, out);
+        }
+        return;
+    }
+
+    if before != after {
+        let (before, after) = if REDACTED_FIELDS.contains(&prefix) {
+            (Value::String("<redacted>".to_string()), Value::String("<redacted>".to_string()))
+        } else {
+            (before.clone(), after.clone())
+        };
+        out.insert(prefix.to_string(), serde_json::json!({ "before": before, "after": after }));
+    }
+}
@@ -0,0 +1,145 @@
+//! Watches the config file for changes and applies whatever it safely
+//! can at runtime — the agent search and RAG settings listed in
+//! `HOT_RELOADABLE_FIELDS` — rather than requiring a restart for every
+//! tweak. Fields that
+//! genuinely need a restart (anything read once at startup, like
+//! `database_url`) are flagged in the change event instead of being
+//! silently ignored or silently applied.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+use super::{load_config, AppConfig};
+
+const CONFIG_CHANGED_EVENT: &str = "config://changed";
+
+/// Dotted JSON paths (matching `AppConfig`'s `camelCase` serialization)
+/// whose new value can be applied live. Anything not listed here needs
+/// a process restart to take effect.
+const HOT_RELOADABLE_FIELDS: &[&str] = &[
+    "agent.lexicalSearchWeight",
+    "agent.vectorSearchWeight",
+    "agent.ragSimilarityThreshold",
+    "agent.ragMaxResults",
+];
+
+/// The live config, shared between whatever reads it and the watcher
+/// that keeps it current.
+pub struct ConfigState {
+    current: RwLock<AppConfig>,
+    config_path: PathBuf,
+}
+
+impl ConfigState {
+    pub fn new(config_path: PathBuf, initial: AppConfig) -> Self {
+        Self { current: RwLock::new(initial), config_path }
+    }
+
+    pub fn current(&self) -> AppConfig {
+        self.current.read().unwrap().clone()
+    }
+
+    pub fn config_path(&self) -> &Path {
+        &self.config_path
+    }
+
+    /// Replaces the live config outright, for a command that's just
+    /// written a new value to `config_path` and reloaded it — the watch
+    /// loop will also pick the write up on its own, but a caller usually
+    /// wants the update to be visible to its own response immediately
+    /// rather than racing the filesystem notification.
+    pub fn set_current(&self, config: AppConfig) {
+        *self.current.write().unwrap() = config;
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigChangeEvent {
+    pub changed_fields: Vec<String>,
+    pub restart_required_fields: Vec<String>,
+}
+
+/// Diffs `before` and `after` via their JSON representations rather
+/// than hand-writing a comparison per field, so a new config field gets
+/// picked up without this needing to change.
+fn diff_fields(before: &AppConfig, after: &AppConfig) -> Vec<String> {
+    let before = serde_json::to_value(before).unwrap_or_default();
+    let after = serde_json::to_value(after).unwrap_or_default();
+    let mut changed = Vec::new();
+    collect_changed_paths("", &before, &after, &mut changed);
+    changed
+}
+
+fn collect_changed_paths(prefix: &str, before: &serde_json::Value, after: &serde_json::Value, out: &mut Vec<String>) {
+    if let (serde_json::Value::Object(b), serde_json::Value::Object(a)) = (before, after) {
+        for (key, a_val) in a {
+            let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+            match b.get(key) {
+                Some(b_val) => collect_changed_paths(&path, b_val, a_val, out),
+                None => out.push(path),
+            }
+        }
+    } else if before != after {
+        out.push(prefix.to_string());
+    }
+}
+
+fn apply_and_emit(app_handle: &AppHandle, state: &ConfigState) {
+    let before = state.current();
+    let after = match load_config(&state.config_path) {
+        Ok(config) => config,
+        Err(err) => {
+            warn!(%err, "failed to reload config, keeping previous values");
+            return;
+        }
+    };
+
+    let changed_fields = diff_fields(&before, &after);
+    if changed_fields.is_empty() {
+        return;
+    }
+
+    let (restart_required_fields, changed_fields): (Vec<String>, Vec<String>) =
+        changed_fields.into_iter().partition(|field| !HOT_RELOADABLE_FIELDS.contains(&field.as_str()));
+
+    // The new file is what takes effect on the next start regardless, so
+    // there's no value in reverting the restart-required fields here —
+    // they're just flagged rather than applied to the running process.
+    *state.current.write().unwrap() = after;
+
+    let event = ConfigChangeEvent { changed_fields, restart_required_fields };
+    info!(?event, "applied config change");
+    if let Err(err) = app_handle.emit(CONFIG_CHANGED_EVENT, &event) {
+        error!(%err, "failed to emit config change event");
+    }
+}
+
+/// Spawns a background watch on `state`'s config file, applying and
+/// announcing changes as they land. The returned watcher must be kept
+/// alive for the duration of the watch (e.g. held in Tauri managed
+/// state) — dropping it stops the watch.
+pub fn watch(app_handle: AppHandle, state: Arc<ConfigState>) -> notify::Result<RecommendedWatcher> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if matches!(&res, Ok(event) if event.kind.is_modify()) {
+            let _ = tx.send(());
+        }
+    })?;
+    watcher.watch(&state.config_path, RecursiveMode::NonRecursive)?;
+
+    tokio::spawn(async move {
+        while rx.recv().await.is_some() {
+            apply_and_emit(&app_handle, &state);
+        }
+    });
+
+    Ok(watcher)
+}
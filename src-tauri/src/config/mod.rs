@@ -0,0 +1,123 @@
+//! Layered configuration: defaults, then an optional config file, then
+//! `DYHT__SECTION__KEY` environment variables, so a deployment or CI run
+//! can override individual values (`DYHT__DATABASE_URL`,
+//! `DYHT__AGENT__RAG_MAX_RESULTS`) without touching the JSON file on
+//! disk. Later layers win. This is the `config` referenced from
+//! `settings::AgentSettings`'s doc comment — persistence, hot-reload,
+//! and the rest of the subsystem grow here as they're needed.
+//!
+//! `paths` resolves where the config file, data, logs, and models
+//! actually live — OS-standard app directories rather than paths
+//! relative to the working directory.
+
+pub mod audit_trail;
+pub mod hot_reload;
+pub mod model_endpoints;
+pub mod paths;
+pub mod persistence;
+pub mod secrets;
+pub mod transfer;
+pub mod validation;
+
+use std::path::{Path, PathBuf};
+
+use figment::providers::{Env, Format, Json, Serialized};
+use figment::Figment;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::settings::AgentSettings;
+
+use model_endpoints::ModelEndpoint;
+
+/// Prefix `figment` strips before matching the rest of an env var name
+/// against a config key, e.g. `DYHT__AGENT__RAG_MAX_RESULTS`.
+pub const ENV_PREFIX: &str = "DYHT__";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error(transparent)]
+    Figment(#[from] figment::Error),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppConfig {
+    /// Name of the `security::vault` entry holding the actual
+    /// connection string — never the connection string itself, so this
+    /// struct (and anything derived from it, like `export_config`'s
+    /// output) is safe to write to the config file or hand to someone
+    /// else without leaking credentials. See `config::secrets`.
+    pub database_url_secret: String,
+    pub model_path: String,
+    /// Named remote model endpoints, replacing the old hardcoded
+    /// endpoint mapping — see `config::model_endpoints`.
+    pub model_endpoints: Vec<ModelEndpoint>,
+    /// Name of the entry in `model_endpoints` currently in use, if any.
+    /// `config::model_endpoints::validate_active_endpoint` is what
+    /// keeps this from ever pointing at an endpoint that doesn't exist.
+    pub active_model_endpoint: Option<String>,
+    pub agent: AgentSettings,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            database_url_secret: secrets::DEFAULT_DATABASE_URL_SECRET.to_string(),
+            model_path: String::new(),
+            model_endpoints: Vec::new(),
+            active_model_endpoint: None,
+            agent: AgentSettings::default(),
+        }
+    }
+}
+
+/// `AppConfig` already holds only a secret *name*, never a secret value,
+/// so exporting it is just serializing it — nothing to redact. Exists as
+/// its own function (rather than leaving callers to serialize
+/// `AppConfig` directly) so that if a genuinely sensitive field is ever
+/// added here, there's one place that has to learn to strip it.
+pub fn export_config(config: &AppConfig) -> Result<String, ConfigError> {
+    Ok(serde_json::to_string_pretty(config)?)
+}
+
+/// Merges defaults, `config_path` (skipped silently if it doesn't
+/// exist, so a fresh install runs on defaults plus whatever's in the
+/// environment), and `DYHT__`-prefixed env vars, in that order.
+pub fn load_config(config_path: &Path) -> Result<AppConfig, ConfigError> {
+    load_config_with_profile(config_path, None)
+}
+
+/// Same as `load_config`, but when `profile` is given, a sibling file
+/// (`<name>.<profile>.<ext>`, e.g. `config.staging.json` next to
+/// `config.json`) is merged in between the base file and env vars — for
+/// running the same install against a staging database or a different
+/// model path without duplicating the whole config.
+pub fn load_config_with_profile(config_path: &Path, profile: Option<&str>) -> Result<AppConfig, ConfigError> {
+    let readable_path = persistence::resolve_readable(config_path);
+    if readable_path != config_path {
+        warn!(
+            primary = %config_path.display(),
+            backup = %readable_path.display(),
+            "config file failed to parse, falling back to newest valid backup"
+        );
+    }
+
+    let mut figment = Figment::from(Serialized::defaults(AppConfig::default())).merge(Json::file(readable_path));
+    if let Some(profile) = profile {
+        figment = figment.merge(Json::file(profile_path(config_path, profile)));
+    }
+    figment = figment.merge(Env::prefixed(ENV_PREFIX).split("__"));
+
+    let mut config: AppConfig = figment.extract()?;
+    config.model_path = paths::migrate_stored_model_path(&config.model_path);
+    Ok(config)
+}
+
+fn profile_path(config_path: &Path, profile: &str) -> PathBuf {
+    let stem = config_path.file_stem().and_then(|s| s.to_str()).unwrap_or("config");
+    let ext = config_path.extension().and_then(|s| s.to_str()).unwrap_or("json");
+    config_path.with_file_name(format!("{stem}.{profile}.{ext}"))
+}
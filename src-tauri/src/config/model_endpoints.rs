@@ -0,0 +1,98 @@
+//! Named model endpoints, configurable at runtime instead of a single
+//! hardcoded model path. `AppConfig::active_model_endpoint` names the
+//! one currently in use; `validate_active_endpoint` is what keeps that
+//! reference honest as endpoints are added and removed.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::security::vault::Vault;
+
+use super::secrets;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelEndpoint {
+    pub name: String,
+    pub url: String,
+    /// Name of the `security::vault` entry holding this endpoint's auth
+    /// token, if it needs one — never the token itself.
+    pub auth_secret: Option<String>,
+    pub timeout_secs: u64,
+    pub health_check_path: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ModelEndpointError {
+    #[error("no model endpoint named '{0}'")]
+    NotFound(String),
+    #[error("a model endpoint named '{0}' already exists")]
+    AlreadyExists(String),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error("endpoint requires auth but no vault is unlocked")]
+    VaultLocked,
+    #[error(transparent)]
+    Vault(#[from] crate::security::vault::VaultError),
+}
+
+/// Checked wherever `active` is set, so a config can never point at an
+/// endpoint that doesn't exist in its own registry.
+pub fn validate_active_endpoint(endpoints: &[ModelEndpoint], active: &str) -> Result<(), ModelEndpointError> {
+    if endpoints.iter().any(|endpoint| endpoint.name == active) {
+        Ok(())
+    } else {
+        Err(ModelEndpointError::NotFound(active.to_string()))
+    }
+}
+
+pub fn add_endpoint(endpoints: &mut Vec<ModelEndpoint>, endpoint: ModelEndpoint) -> Result<(), ModelEndpointError> {
+    if endpoints.iter().any(|existing| existing.name == endpoint.name) {
+        return Err(ModelEndpointError::AlreadyExists(endpoint.name));
+    }
+    endpoints.push(endpoint);
+    Ok(())
+}
+
+pub fn remove_endpoint(endpoints: &mut Vec<ModelEndpoint>, name: &str) -> Result<(), ModelEndpointError> {
+    let before = endpoints.len();
+    endpoints.retain(|endpoint| endpoint.name != name);
+    if endpoints.len() == before {
+        return Err(ModelEndpointError::NotFound(name.to_string()));
+    }
+    Ok(())
+}
+
+/// GETs `health_check_path` against the endpoint's `url` — confirms
+/// something answers, not that the model behind it is actually healthy.
+///
+/// Takes an already-resolved `auth_token` rather than a `&Vault`, so
+/// callers resolve the secret (a quick, synchronous lock) before this
+/// `await`s, instead of holding the vault's mutex across a network call.
+pub async fn test_endpoint(endpoint: &ModelEndpoint, auth_token: Option<&str>) -> Result<(), ModelEndpointError> {
+    let url = format!("{}{}", endpoint.url.trim_end_matches('/'), endpoint.health_check_path);
+    let mut request = reqwest::Client::new().get(url).timeout(Duration::from_secs(endpoint.timeout_secs));
+
+    if endpoint.auth_secret.is_some() {
+        let token = auth_token.ok_or(ModelEndpointError::VaultLocked)?;
+        request = request.bearer_auth(token);
+    }
+
+    request.send().await?.error_for_status()?;
+    Ok(())
+}
+
+/// Resolves `endpoint`'s auth secret (if any) out of `vault` — the
+/// synchronous half of testing an endpoint, kept separate from
+/// `test_endpoint` so the vault lock never has to be held across an
+/// `await`.
+pub fn resolve_auth_token(endpoint: &ModelEndpoint, vault: Option<&Vault>) -> Result<Option<String>, ModelEndpointError> {
+    match &endpoint.auth_secret {
+        None => Ok(None),
+        Some(secret_name) => {
+            let vault = vault.ok_or(ModelEndpointError::VaultLocked)?;
+            Ok(Some(secrets::resolve_secret(vault, secret_name)?))
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Platform-correct locations for config, data, logs, and models, via
+//! `directories::ProjectDirs`, replacing the old practice of resolving
+//! `./config`, `./data`, `./logs` relative to wherever the process
+//! happened to be launched from.
+
+use std::path::{Path, PathBuf};
+
+use directories::ProjectDirs;
+use tracing::{info, warn};
+
+const QUALIFIER: &str = "com";
+const ORGANIZATION: &str = "dyhtcreator";
+const APPLICATION: &str = "DYHT";
+
+/// Old working-directory-relative locations, kept around only for
+/// `migrate_legacy_dirs` to find and retire.
+const LEGACY_CONFIG_DIR: &str = "config";
+const LEGACY_DATA_DIR: &str = "data";
+const LEGACY_LOG_DIR: &str = "logs";
+const LEGACY_MODEL_DIR: &str = "models";
+
+fn project_dirs() -> ProjectDirs {
+    ProjectDirs::from(QUALIFIER, ORGANIZATION, APPLICATION).expect("no home directory available for this platform")
+}
+
+pub fn config_dir() -> PathBuf {
+    project_dirs().config_dir().to_path_buf()
+}
+
+/// The config file `load_config` reads by default — `--config` on the
+/// CLI overrides this outright rather than joining onto it.
+pub fn default_config_file() -> PathBuf {
+    config_dir().join("config.json")
+}
+
+pub fn data_dir() -> PathBuf {
+    project_dirs().data_dir().to_path_buf()
+}
+
+pub fn log_dir() -> PathBuf {
+    project_dirs().data_dir().join("logs")
+}
+
+pub fn model_dir() -> PathBuf {
+    project_dirs().data_dir().join("models")
+}
+
+/// Moves whatever's left under the legacy `./config`, `./data`,
+/// `./logs` directories into their OS-standard replacements. Safe to
+/// call on every startup — each directory is a no-op once it's empty
+/// and removed, so this only ever does real work the first time a build
+/// with this code runs against an existing install.
+pub fn migrate_legacy_dirs() -> std::io::Result<()> {
+    migrate_one(Path::new(LEGACY_CONFIG_DIR), &config_dir())?;
+    migrate_one(Path::new(LEGACY_DATA_DIR), &data_dir())?;
+    migrate_one(Path::new(LEGACY_LOG_DIR), &log_dir())?;
+    Ok(())
+}
+
+fn migrate_one(legacy: &Path, target: &Path) -> std::io::Result<()> {
+    if !legacy.exists() {
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(target)?;
+    for entry in std::fs::read_dir(legacy)? {
+        let entry = entry?;
+        let dest = target.join(entry.file_name());
+        if dest.exists() {
+            warn!(path = %dest.display(), "skipping legacy file migration, destination already exists");
+            continue;
+        }
+        std::fs::rename(entry.path(), &dest)?;
+    }
+
+    match std::fs::remove_dir(legacy) {
+        Ok(()) => info!(from = %legacy.display(), to = %target.display(), "migrated legacy directory to OS-standard location"),
+        Err(err) => warn!(%err, path = %legacy.display(), "legacy directory left in place, not empty after migration"),
+    }
+    Ok(())
+}
+
+/// Repoints a `model_path` stored in config from the old
+/// `./models/<file>` convention onto the new OS-standard model
+/// directory, leaving anything else (an absolute path, one already
+/// under the new location) untouched.
+pub fn migrate_stored_model_path(model_path: &str) -> String {
+    let old_prefix = format!("{LEGACY_MODEL_DIR}/");
+    match model_path.strip_prefix(&old_prefix) {
+        Some(file_name) => model_dir().join(file_name).to_string_lossy().into_owned(),
+        None => model_path.to_string(),
+    }
+}
@@ -0,0 +1,68 @@
+//! Safe writes to the config file on disk. `save` never leaves the file
+//! half-written if the process dies mid-write, and keeps the last few
+//! known-good copies so `resolve_readable` can recover from a corrupted
+//! file instead of failing the next startup outright.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// How many rotated backups to keep: `config.json.bak.1` (newest)
+/// through `config.json.bak.BACKUP_COUNT` (oldest).
+pub const BACKUP_COUNT: usize = 5;
+
+pub fn backup_path(config_path: &Path, index: usize) -> PathBuf {
+    let mut name = config_path.file_name().and_then(|n| n.to_str()).unwrap_or("config.json").to_string();
+    name.push_str(&format!(".bak.{index}"));
+    config_path.with_file_name(name)
+}
+
+/// Writes `contents` to `path`, rotating the existing file into the
+/// backup chain first, then landing the new content via a write-then-
+/// rename so a reader (or a crash) never sees a partially written file —
+/// a rename is atomic as long as the temp file is on the same
+/// filesystem, which `with_extension` guarantees by staying in `path`'s
+/// own directory.
+pub fn save(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    if path.exists() {
+        rotate_backups(path)?;
+    }
+
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, contents)?;
+    std::fs::rename(&tmp_path, path)
+}
+
+fn rotate_backups(path: &Path) -> io::Result<()> {
+    for index in (1..BACKUP_COUNT).rev() {
+        let from = backup_path(path, index);
+        let to = backup_path(path, index + 1);
+        if from.exists() {
+            std::fs::rename(from, to)?;
+        }
+    }
+    std::fs::copy(path, backup_path(path, 1))?;
+    Ok(())
+}
+
+/// `path` itself if it holds valid JSON, or else the newest backup that
+/// does. What `load_config` actually reads from, so a file corrupted by
+/// a crash mid-write doesn't take the next startup down with it.
+pub fn resolve_readable(path: &Path) -> PathBuf {
+    if is_valid_json(path) {
+        return path.to_path_buf();
+    }
+    for index in 1..=BACKUP_COUNT {
+        let backup = backup_path(path, index);
+        if is_valid_json(&backup) {
+            return backup;
+        }
+    }
+    path.to_path_buf()
+}
+
+fn is_valid_json(path: &Path) -> bool {
+    std::fs::read_to_string(path).ok().and_then(|raw| serde_json::from_str::<serde_json::Value>(&raw).ok()).is_some()
+}
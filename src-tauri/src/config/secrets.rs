@@ -0,0 +1,20 @@
+//! Secret-bearing config values don't live in `AppConfig` or the config
+//! file at all — `AppConfig` holds only the name of a `security::vault`
+//! entry, and the actual value is resolved through the vault at the
+//! point of use. This is what keeps `config::export_config` safe to
+//! hand to someone else unredacted: there's never a secret in the
+//! struct to accidentally export.
+
+use crate::security::vault::{Vault, VaultError};
+
+/// Vault key `AppConfig::database_url_secret` points at by default.
+/// Nothing stops a deployment repointing it at a different vault entry
+/// to share one vault across multiple configs.
+pub const DEFAULT_DATABASE_URL_SECRET: &str = "database_url";
+
+/// Looks up `secret_name` in `vault`, for resolving
+/// `AppConfig::database_url_secret` into the connection string it
+/// actually refers to.
+pub fn resolve_secret(vault: &Vault, secret_name: &str) -> Result<String, VaultError> {
+    vault.get(secret_name).map(str::to_string)
+}
@@ -0,0 +1,124 @@
+//! Moving a whole `AppConfig` across machines: `export_to_file` templates
+//! out install-specific paths so the file is meaningful somewhere else,
+//! and `import_from_file` reverses that, validates the result, and
+//! builds a before/after diff so the caller can show the user what
+//! would actually change before applying it.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::audit_trail::REDACTED_FIELDS;
+use super::validation::{validate_active_model_endpoint, validate_agent_settings, FieldViolation};
+use super::{paths, AppConfig, ConfigError};
+
+const MODEL_DIR_PLACEHOLDER: &str = "{model_dir}";
+
+/// One field that would change if an imported config were applied.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigDiffEntry {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportPreview {
+    pub config: AppConfig,
+    pub diff: Vec<ConfigDiffEntry>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransferError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error("imported config failed validation")]
+    Validation(Vec<FieldViolation>),
+}
+
+/// Writes `config` to `path` with `model_path` templated to
+/// `{model_dir}/<relative part>` whenever it lives under this machine's
+/// OS-standard model directory — there are no raw secret values to
+/// redact (`AppConfig` only ever holds secret *names*, see
+/// `config::secrets`), but an absolute path baked into a shared config
+/// would silently point at the wrong machine once imported elsewhere.
+pub fn export_to_file(config: &AppConfig, path: &Path) -> Result<(), TransferError> {
+    let mut templated = config.clone();
+    templated.model_path = template_model_path(&config.model_path);
+    let json = serde_json::to_string_pretty(&templated).map_err(ConfigError::from)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Reads and validates the config at `path` (untemplating `model_path`
+/// back onto this machine first) without applying it, returning it
+/// alongside a diff against `current` so the caller can show the user
+/// what would change.
+pub fn import_from_file(path: &Path, current: &AppConfig) -> Result<ImportPreview, TransferError> {
+    let raw = std::fs::read_to_string(path)?;
+    let mut config: AppConfig = serde_json::from_str(&raw).map_err(ConfigError::from)?;
+    config.model_path = untemplate_model_path(&config.model_path);
+
+    let mut violations = Vec::new();
+    if let Err(agent_violations) = validate_agent_settings(&config.agent) {
+        violations.extend(agent_violations);
+    }
+    if let Err(endpoint_violations) =
+        validate_active_model_endpoint(&config.model_endpoints, config.active_model_endpoint.as_deref())
+    {
+        violations.extend(endpoint_violations);
+    }
+    if !violations.is_empty() {
+        return Err(TransferError::Validation(violations));
+    }
+
+    let diff = diff_for_preview(current, &config);
+    Ok(ImportPreview { config, diff })
+}
+
+fn template_model_path(model_path: &str) -> String {
+    let model_dir = paths::model_dir();
+    match Path::new(model_path).strip_prefix(&model_dir) {
+        Ok(relative) => format!("{MODEL_DIR_PLACEHOLDER}/{}", relative.to_string_lossy()),
+        Err(_) => model_path.to_string(),
+    }
+}
+
+fn untemplate_model_path(model_path: &str) -> String {
+    match model_path.strip_prefix(MODEL_DIR_PLACEHOLDER) {
+        Some(relative) => paths::model_dir().join(relative.trim_start_matches('/')).to_string_lossy().into_owned(),
+        None => model_path.to_string(),
+    }
+}
+
+fn diff_for_preview(before: &AppConfig, after: &AppConfig) -> Vec<ConfigDiffEntry> {
+    let before = serde_json::to_value(before).unwrap_or_default();
+    let after = serde_json::to_value(after).unwrap_or_default();
+    let mut out = Vec::new();
+    collect_diff("", &before, &after, &mut out);
+    out
+}
+
+fn collect_diff(prefix: &str, before: &Value, after: &Value, out: &mut Vec<ConfigDiffEntry>) {
+    if let (Value::Object(b), Value::Object(a)) = (before, after) {
+        for (key, a_val) in a {
+            let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+            let b_val = b.get(key).cloned().unwrap_or(Value::Null);
+            collect_diff(&path, &b_val, a_val, out);
+        }
+        return;
+    }
+
+    if before != after {
+        let (before, after) = if REDACTED_FIELDS.contains(&prefix) {
+            (Value::String("<redacted>".to_string()), Value::String("<redacted>".to_string()))
+        } else {
+            (before.clone(), after.clone())
+        };
+        out.push(ConfigDiffEntry { field: prefix.to_string(), before, after });
+    }
+}
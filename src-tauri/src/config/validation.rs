@@ -0,0 +1,146 @@
+//! Structured, field-level config validation. Returns every violation
+//! found in one pass rather than bailing out on the first, so the
+//! settings UI can highlight every wrong field in a single round trip
+//! instead of a fix-one-resubmit-find-the-next loop.
+
+use serde::Serialize;
+
+use crate::settings::AgentSettings;
+
+use super::model_endpoints::ModelEndpoint;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldViolation {
+    /// Dotted path matching the field's `camelCase` JSON name, e.g.
+    /// `agent.ragMaxResults`.
+    pub field: String,
+    pub message: String,
+    pub allowed: String,
+}
+
+impl FieldViolation {
+    fn new(field: &str, message: impl Into<String>, allowed: &str) -> Self {
+        Self { field: field.to_string(), message: message.into(), allowed: allowed.to_string() }
+    }
+}
+
+/// Validates every field of `settings`, collecting all violations
+/// rather than short-circuiting on the first.
+pub fn validate_agent_settings(settings: &AgentSettings) -> Result<(), Vec<FieldViolation>> {
+    let mut violations = Vec::new();
+
+    if !(0.0..=1.0).contains(&settings.lexical_search_weight) {
+        violations.push(FieldViolation::new(
+            "agent.lexicalSearchWeight",
+            format!("{} is out of range", settings.lexical_search_weight),
+            "0.0 to 1.0",
+        ));
+    }
+    if !(0.0..=1.0).contains(&settings.vector_search_weight) {
+        violations.push(FieldViolation::new(
+            "agent.vectorSearchWeight",
+            format!("{} is out of range", settings.vector_search_weight),
+            "0.0 to 1.0",
+        ));
+    }
+    if !(0.0..=1.0).contains(&settings.rag_similarity_threshold) {
+        violations.push(FieldViolation::new(
+            "agent.ragSimilarityThreshold",
+            format!("{} is out of range", settings.rag_similarity_threshold),
+            "0.0 to 1.0",
+        ));
+    }
+    if settings.rag_max_results <= 0 {
+        violations.push(FieldViolation::new(
+            "agent.ragMaxResults",
+            format!("{} must be positive", settings.rag_max_results),
+            "> 0",
+        ));
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// Confirms `active` (if set) actually names one of `endpoints`, so a
+/// config can't reference a model endpoint that doesn't exist.
+pub fn validate_active_model_endpoint(
+    endpoints: &[ModelEndpoint],
+    active: Option<&str>,
+) -> Result<(), Vec<FieldViolation>> {
+    let Some(active) = active else { return Ok(()) };
+
+    if endpoints.iter().any(|endpoint| endpoint.name == active) {
+        Ok(())
+    } else {
+        let known = endpoints.iter().map(|e| e.name.as_str()).collect::<Vec<_>>().join(", ");
+        Err(vec![FieldViolation::new(
+            "activeModelEndpoint",
+            format!("'{active}' does not match any configured endpoint"),
+            if known.is_empty() { "no endpoints configured" } else { &known },
+        )])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(name: &str) -> ModelEndpoint {
+        ModelEndpoint {
+            name: name.to_string(),
+            url: "https://example.test".to_string(),
+            auth_secret: None,
+            timeout_secs: 30,
+            health_check_path: "/health".to_string(),
+        }
+    }
+
+    #[test]
+    fn default_settings_are_valid() {
+        assert!(validate_agent_settings(&AgentSettings::default()).is_ok());
+    }
+
+    #[test]
+    fn a_search_weight_above_one_is_a_violation() {
+        let mut settings = AgentSettings::default();
+        settings.lexical_search_weight = 1.5;
+        let violations = validate_agent_settings(&settings).unwrap_err();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "agent.lexicalSearchWeight");
+    }
+
+    #[test]
+    fn multiple_violations_are_all_reported_in_one_pass() {
+        let settings = AgentSettings {
+            lexical_search_weight: -1.0,
+            vector_search_weight: 2.0,
+            rag_similarity_threshold: 0.15,
+            rag_max_results: 0,
+        };
+        let violations = validate_agent_settings(&settings).unwrap_err();
+        assert_eq!(violations.len(), 3);
+    }
+
+    #[test]
+    fn no_active_endpoint_is_always_valid() {
+        assert!(validate_active_model_endpoint(&[], None).is_ok());
+    }
+
+    #[test]
+    fn an_active_endpoint_matching_a_configured_name_is_valid() {
+        let endpoints = vec![endpoint("primary")];
+        assert!(validate_active_model_endpoint(&endpoints, Some("primary")).is_ok());
+    }
+
+    #[test]
+    fn an_active_endpoint_with_no_matching_name_is_a_violation() {
+        let endpoints = vec![endpoint("primary")];
+        let violations = validate_active_model_endpoint(&endpoints, Some("missing")).unwrap_err();
+        assert_eq!(violations[0].field, "activeModelEndpoint");
+    }
+}
@@ -0,0 +1,210 @@
+pub mod audit;
+pub mod commands;
+pub mod config;
+pub mod memory;
+pub mod security;
+pub mod session;
+pub mod settings;
+pub mod tracing_setup;
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use tauri::{Manager, WebviewUrl, WebviewWindowBuilder};
+use tracing::warn;
+
+use config::hot_reload::ConfigState;
+use config::AppConfig;
+use security::admin_auth::{AdminAuth, DEFAULT_ADMIN_CODE};
+use security::manager::SecurityManager;
+use security::modifications::ModificationRegistry;
+use security::rbac::UserStore;
+use security::rules::SecurityRules;
+use security::vault::Vault;
+use tracing_setup::ReloadHandle;
+
+/// Brings up the app: opens the audit log, loads (or initializes) the
+/// admin credential, wires every `#[tauri::command]` in `commands::*`
+/// up to the frontend, and spawns the background upkeep tasks
+/// (`audit::reconcile`, `audit::rotation`, `audit::sampling`,
+/// `config::hot_reload`) that otherwise never ran. `database_url` and
+/// `vault` are `None` when the caller (see `main.rs`) couldn't resolve
+/// them — memory and vault commands are simply unavailable in that
+/// case rather than the app failing to start. `headless` skips opening
+/// the main window (no `app.windows` entries are declared in
+/// `tauri.conf.json`, so nothing is shown unless this creates one) but
+/// everything else above still runs, since CI/headless hosts still
+/// want the audit trail and background tasks live.
+pub fn run(config: AppConfig, config_path: PathBuf, database_url: Option<String>, vault: Option<Vault>, reload_handle: ReloadHandle, headless: bool) {
+    let log_dir = config::paths::log_dir();
+    audit::crash::install_panic_hook(log_dir.clone());
+    let marker_dir = log_dir.clone();
+
+    let config_state = Arc::new(ConfigState::new(config_path, config));
+    let admin_auth = Arc::new(default_admin_auth());
+
+    let mut builder = tauri::Builder::default()
+        .plugin(tauri_plugin_notification::init())
+        .manage(Arc::clone(&admin_auth))
+        .manage(ModificationRegistry::default())
+        .manage(UserStore::default())
+        .manage(Arc::clone(&config_state))
+        .manage(reload_handle)
+        .invoke_handler(tauri::generate_handler![
+            commands::audit::search_logs_command,
+            commands::audit::query_logs_command,
+            commands::audit::export_logs_command,
+            commands::audit::archive_logs_command,
+            commands::audit::open_archive_command,
+            commands::audit::get_log_statistics_command,
+            commands::audit::reconcile_audit_index_command,
+            commands::audit::get_app_metrics_command,
+            commands::audit::verify_log_integrity_command,
+            commands::audit::sign_log_file_command,
+            commands::audit::verify_log_signature_command,
+            commands::audit::audit_signing_public_key_command,
+            commands::audit::compress_log_file_command,
+            commands::audit::get_audit_settings_command,
+            commands::audit::set_audit_settings_command,
+            commands::audit::rotate_now_command,
+            commands::audit::drain_shipper_spool_command,
+            commands::audit::get_audit_entries_command,
+            commands::audit::get_audit_entry_command,
+            commands::audit::get_alert_rules_command,
+            commands::audit::set_alert_rules_command,
+            commands::audit::list_triggered_alerts_command,
+            commands::audit::get_crash_reports_command,
+            commands::audit::is_audit_encryption_enabled_command,
+            commands::audit::get_sampling_rules_command,
+            commands::audit::set_sampling_rules_command,
+            commands::config::get_config_command,
+            commands::config::export_config_command,
+            commands::config::update_agent_settings_command,
+            commands::config::get_config_history_command,
+            commands::config::add_model_endpoint_command,
+            commands::config::remove_model_endpoint_command,
+            commands::config::set_active_model_endpoint_command,
+            commands::config::test_model_endpoint_command,
+            commands::config::export_config_to_file_command,
+            commands::config::preview_config_import_command,
+            commands::config::apply_imported_config_command,
+            commands::memory::get_memory_stats,
+            commands::memory::delete_memory,
+            commands::memory::restore_memory,
+            commands::memory::list_trashed_memories,
+            commands::security::vault_set,
+            commands::security::vault_get,
+            commands::security::vault_list,
+            commands::security::vault_delete,
+            commands::security::get_security_report,
+            commands::security::reload_policy,
+            commands::security::add_modification_comment,
+            commands::security::list_modification_comments,
+            commands::security::simulate_modification,
+            commands::security::issue_session_command,
+            commands::security::validate_session_command,
+            commands::security::transition_lockdown_command,
+            commands::security::lockdown_state_command,
+            commands::security::propose_modification,
+            commands::security::open_approval_command,
+            commands::security::approve_modification_command,
+            commands::security::reject_modification_command,
+            commands::security::analyze_modification_command,
+            commands::security::apply_modification_command,
+            commands::security::check_anomaly_lockdown_command,
+            commands::security::enroll_totp_command,
+            commands::security::verify_totp_command,
+            commands::security::create_user_command,
+            commands::security::assign_role_command,
+            commands::security::list_users_command,
+            commands::system::get_log_level_command,
+            commands::system::set_log_level_command,
+        ]);
+
+    if let Some(vault) = vault {
+        builder = builder.manage(Mutex::new(vault));
+    }
+    if let Some(database_url) = database_url {
+        match sqlx::postgres::PgPoolOptions::new().connect_lazy(&database_url) {
+            Ok(pool) => builder = builder.manage(pool),
+            Err(err) => warn!(%err, "failed to build database pool, memory commands will be unavailable"),
+        }
+    }
+
+    builder
+        .setup(move |app| {
+            let app_handle = app.handle().clone();
+            let mut writer = audit::writer::LogWriter::open(&log_dir)?.with_index().with_app_handle(app_handle.clone());
+            let crash_reports = audit::crash::CrashReports::new(audit::crash::recover_crash_reports(&writer, &log_dir));
+            // Only after recovery has looked for the previous run's marker.
+            if let Err(err) = audit::crash::mark_running(&log_dir) {
+                warn!(%err, "failed to write crash-recovery running marker");
+            }
+            writer = writer.with_encryption();
+            let writer = Arc::new(writer);
+
+            audit::reconcile::spawn(Arc::clone(&writer));
+            audit::rotation::spawn(Arc::clone(&writer));
+            audit::sampling::spawn(Arc::clone(&writer));
+            // Dropping the watcher stops the watch, so it's kept in managed
+            // state for the life of the app.
+            match config::hot_reload::watch(app_handle, Arc::clone(&config_state)) {
+                Ok(watcher) => {
+                    app.manage(Mutex::new(watcher));
+                }
+                Err(err) => warn!(%err, "failed to start config file watcher, hot reload disabled for this run"),
+            }
+
+            // Built here, not alongside the other `.manage()` calls above,
+            // since it needs the writer this same closure just opened —
+            // every admin-auth attempt, lockdown transition, rule
+            // violation and approval decision now reaches the persistent,
+            // hash-chained audit log instead of only ever being printed
+            // via `tracing`.
+            let audit_logger = Arc::new(security::audit_log::LogWriterAuditLogger::new(Arc::clone(&writer)));
+            let security_manager = SecurityManager::with_audit_logger(Arc::clone(&admin_auth), SecurityRules::default(), audit_logger);
+            app.manage(security_manager);
+
+            app.manage(writer);
+            app.manage(crash_reports);
+
+            if !headless {
+                WebviewWindowBuilder::new(app, "main", WebviewUrl::App("index.html".into()))
+                    .title("DYHT")
+                    .inner_size(1100.0, 720.0)
+                    .build()?;
+            }
+
+            Ok(())
+        })
+        .build(tauri::generate_context!())
+        .expect("error while building the dyht application")
+        .run(move |_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                audit::crash::clear_running_marker(&marker_dir);
+            }
+        });
+}
+
+/// Loads the admin credential from the OS keyring, falling back to a
+/// freshly-hashed `DEFAULT_ADMIN_CODE` the first time (or whenever) the
+/// keyring has no entry yet. Built once and shared (see `Arc::clone`
+/// above) so rotating the code through one command updates every
+/// consumer immediately rather than leaving a second, stale copy
+/// reading the same keyring key until restart.
+///
+/// A keyring backend being unavailable (no Secret Service/D-Bus
+/// session, e.g. on a headless/CI/container host) isn't treated as
+/// fatal: it's the normal state for `--headless` runs, which this same
+/// function's caller needs to keep starting. It falls back to the
+/// default hash in memory for this run, loudly, rather than crashing.
+fn default_admin_auth() -> AdminAuth {
+    let default_hash = bcrypt::hash(DEFAULT_ADMIN_CODE, bcrypt::DEFAULT_COST).expect("failed to hash the default admin code");
+    match AdminAuth::load_or_init(default_hash.clone()) {
+        Ok(auth) => auth,
+        Err(err) => {
+            warn!(%err, "OS keyring unavailable, falling back to the default admin code for this run");
+            AdminAuth::new(default_hash)
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// Prevents an additional console window on Windows in release builds.
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use dyht_lib::config::{load_config_with_profile, paths, secrets};
+use dyht_lib::security::vault::Vault;
+use dyht_lib::tracing_setup;
+use tracing::{info, warn};
+
+/// Flags for running the agent outside the normal windowed launch —
+/// CI, scripted one-off runs, and hosts with no display.
+#[derive(Debug, Parser)]
+#[command(name = "dyht", version, about = "Dwight DVR for Sound")]
+struct Cli {
+    /// Config file to load. Defaults to the OS-standard config
+    /// directory's `config.json` (see `config::paths::default_config_file`).
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Named profile whose `<config>.<profile>.json` sibling file is
+    /// layered on top of the base config, e.g. `--profile staging`.
+    #[arg(long)]
+    profile: Option<String>,
+
+    /// Run without opening a window, for CI and headless hosts.
+    #[arg(long)]
+    headless: bool,
+
+    /// Overrides the tracing filter for this run (e.g. `debug`,
+    /// `dyht=trace,info`), taking priority over `RUST_LOG`.
+    #[arg(long)]
+    log_level: Option<String>,
+
+    /// Connection string to use for this run instead of resolving
+    /// `database_url_secret` through the vault — handy for CI, which
+    /// usually doesn't want to deal with an encrypted vault at all.
+    #[arg(long)]
+    db_url: Option<String>,
+
+    /// Master password to unlock the on-disk vault with at startup.
+    /// Without it (and without `--db-url`), this run has no vault and
+    /// no resolvable `database_url_secret`.
+    #[arg(long)]
+    vault_password: Option<String>,
+}
+
+/// Resolves the connection string to actually connect with: the CLI
+/// override if given, otherwise the vault entry `config.database_url_secret`
+/// names.
+fn resolve_database_url(cli_db_url: Option<&str>, vault: Option<&Vault>, config: &dyht_lib::config::AppConfig) -> Result<String, String> {
+    if let Some(url) = cli_db_url {
+        return Ok(url.to_string());
+    }
+    let vault = vault.ok_or("no --db-url override given and no vault unlocked to resolve database_url_secret from")?;
+    secrets::resolve_secret(vault, &config.database_url_secret).map_err(|err| err.to_string())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let reload_handle = tracing_setup::init_with(cli.log_level.as_deref());
+
+    let config_path = cli.config.unwrap_or_else(paths::default_config_file);
+    let config = match load_config_with_profile(&config_path, cli.profile.as_deref()) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("failed to load config from {}: {err}", config_path.display());
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(err) = paths::migrate_legacy_dirs() {
+        warn!(%err, "failed to migrate legacy config/data/log directories");
+    }
+
+    // `--vault-password` is the only non-interactive way this tree has
+    // to unlock a vault today; without it (or without a matching
+    // `--db-url` override) scripted runs just proceed with no vault and
+    // no resolvable database_url, same as before this flag existed.
+    let vault = cli.vault_password.as_deref().and_then(|password| {
+        match Vault::unlock(&paths::data_dir().join("vault.json"), password) {
+            Ok(vault) => Some(vault),
+            Err(err) => {
+                warn!(%err, "failed to unlock vault with the given --vault-password, continuing without one");
+                None
+            }
+        }
+    });
+
+    match resolve_database_url(cli.db_url.as_deref(), vault.as_ref(), &config) {
+        Ok(database_url) => {
+            info!(headless = cli.headless, config = %config_path.display(), "starting");
+            dyht_lib::run(config, config_path, Some(database_url), vault, reload_handle, cli.headless);
+        }
+        Err(err) => {
+            if cli.headless {
+                eprintln!("could not resolve database connection: {err}");
+                std::process::exit(1);
+            }
+            warn!(%err, "starting without a resolvable database connection; memory commands will be unavailable");
+            dyht_lib::run(config, config_path, None, vault, reload_handle, cli.headless);
+        }
+    }
+}
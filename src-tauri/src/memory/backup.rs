@@ -0,0 +1,60 @@
+//! Database backup and restore, shelling out to `pg_dump`/`pg_restore`
+//! rather than reimplementing dump logic, since those tools already
+//! handle the full schema (including the pgvector extension) correctly.
+
+use std::path::Path;
+use std::process::Stdio;
+
+use tokio::process::Command;
+use tracing::info;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BackupError {
+    #[error("failed to spawn {0}: {1}")]
+    Spawn(&'static str, std::io::Error),
+    #[error("{0} exited with status {1}")]
+    NonZeroExit(&'static str, std::process::ExitStatus),
+}
+
+/// Writes a custom-format `pg_dump` backup of the database to `dest`.
+pub async fn backup(database_url: &str, dest: &Path) -> Result<(), BackupError> {
+    let status = Command::new("pg_dump")
+        .arg("--format=custom")
+        .arg("--file")
+        .arg(dest)
+        .arg(database_url)
+        .stdout(Stdio::null())
+        .status()
+        .await
+        .map_err(|e| BackupError::Spawn("pg_dump", e))?;
+
+    if !status.success() {
+        return Err(BackupError::NonZeroExit("pg_dump", status));
+    }
+    info!(dest = %dest.display(), "memory database backup complete");
+    Ok(())
+}
+
+/// Restores a `pg_dump --format=custom` backup into the database at
+/// `database_url`. The target database must already exist; with
+/// `--clean --if-exists`, objects in the backup are dropped (where
+/// present) and recreated, so it need not be empty. This does not drop
+/// or create databases.
+pub async fn restore(database_url: &str, source: &Path) -> Result<(), BackupError> {
+    let status = Command::new("pg_restore")
+        .arg("--clean")
+        .arg("--if-exists")
+        .arg("--dbname")
+        .arg(database_url)
+        .arg(source)
+        .stdout(Stdio::null())
+        .status()
+        .await
+        .map_err(|e| BackupError::Spawn("pg_restore", e))?;
+
+    if !status.success() {
+        return Err(BackupError::NonZeroExit("pg_restore", status));
+    }
+    info!(source = %source.display(), "memory database restore complete");
+    Ok(())
+}
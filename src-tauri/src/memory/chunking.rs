@@ -0,0 +1,72 @@
+//! Splits long content into overlapping chunks before embedding, since a
+//! single embedding vector loses precision once the source text is much
+//! longer than the model's effective context.
+
+const DEFAULT_CHUNK_SIZE: usize = 1000;
+const DEFAULT_OVERLAP: usize = 200;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkingConfig {
+    /// Target chunk size in bytes, rounded to a UTF-8 character boundary.
+    pub chunk_size: usize,
+    /// How many bytes of overlap to keep between consecutive chunks
+    /// (rounded to a character boundary), so a fact split across a
+    /// boundary isn't lost.
+    pub overlap: usize,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self { chunk_size: DEFAULT_CHUNK_SIZE, overlap: DEFAULT_OVERLAP }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    pub index: usize,
+    pub text: String,
+}
+
+/// Splits `content` on paragraph boundaries where possible, packing
+/// paragraphs into chunks up to `config.chunk_size` bytes and repeating
+/// the trailing `config.overlap` bytes at the start of the next chunk.
+/// Both are rounded to UTF-8 character boundaries, so a chunk never
+/// splits a multi-byte character.
+pub fn chunk_content(content: &str, config: &ChunkingConfig) -> Vec<Chunk> {
+    if content.len() <= config.chunk_size {
+        return vec![Chunk { index: 0, text: content.to_string() }];
+    }
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let bytes = content.as_bytes();
+
+    while start < bytes.len() {
+        let mut end = (start + config.chunk_size).min(bytes.len());
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end < bytes.len() {
+            if let Some(boundary) = content[start..end].rfind("\n\n") {
+                end = start + boundary;
+            }
+        }
+        // Don't emit an empty chunk if a found boundary lands on `start`.
+        let mut end = end.max(start + 1).min(bytes.len());
+        while !content.is_char_boundary(end) {
+            end += 1;
+        }
+
+        chunks.push(Chunk { index: chunks.len(), text: content[start..end].to_string() });
+
+        if end >= bytes.len() {
+            break;
+        }
+        start = end.saturating_sub(config.overlap);
+        while !content.is_char_boundary(start) {
+            start -= 1;
+        }
+    }
+
+    chunks
+}
@@ -0,0 +1,40 @@
+//! Ties together the individual cleanup passes (expired TTLs, trash past
+//! retention, consolidation) into one scheduled task.
+
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tokio::time::interval;
+use tracing::{error, info};
+
+use crate::memory::consolidation::{consolidate_once, ConsolidationConfig};
+use crate::memory::expiry::purge_expired;
+use crate::memory::trash::purge_trash;
+
+/// Spawns a background task that runs every `every` and performs, in
+/// order: expired-TTL purge, trash purge, then memory consolidation.
+/// Each step's error is logged independently so one failing step
+/// doesn't block the others.
+pub fn spawn(pool: PgPool, every: Duration, consolidation: ConsolidationConfig) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        loop {
+            ticker.tick().await;
+
+            match purge_expired(&pool).await {
+                Ok(count) => info!(count, "purged expired memories"),
+                Err(err) => error!(%err, "failed to purge expired memories"),
+            }
+
+            match purge_trash(&pool).await {
+                Ok(count) => info!(count, "purged trashed memories past retention"),
+                Err(err) => error!(%err, "failed to purge trash"),
+            }
+
+            match consolidate_once(&pool, &consolidation).await {
+                Ok(count) => info!(count, "consolidated duplicate memories"),
+                Err(err) => error!(%err, "failed to consolidate memories"),
+            }
+        }
+    })
+}
@@ -0,0 +1,60 @@
+//! Transparently compresses large memory content with zstd. Entries
+//! above `COMPRESSION_THRESHOLD_BYTES` are stored with their full text
+//! compressed into `content_compressed`, and `content` truncated to a
+//! preview so lexical search still has something to index without
+//! decompressing on every query.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+const COMPRESSION_THRESHOLD_BYTES: usize = 8192;
+const PREVIEW_BYTES: usize = 512;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompressionError {
+    #[error("compression failed: {0}")]
+    Zstd(#[from] std::io::Error),
+    #[error(transparent)]
+    Db(#[from] sqlx::Error),
+}
+
+/// If `content` is large, compresses it and stores the compressed bytes
+/// plus a preview; otherwise stores `content` unchanged.
+pub async fn store_compressed(
+    pool: &PgPool,
+    id: Uuid,
+    content: &str,
+) -> Result<(), CompressionError> {
+    if content.len() <= COMPRESSION_THRESHOLD_BYTES {
+        return Ok(());
+    }
+
+    let compressed = zstd::stream::encode_all(content.as_bytes(), 0)?;
+    let preview: String = content.chars().take(PREVIEW_BYTES).collect();
+
+    sqlx::query("UPDATE memories SET content = $1, content_compressed = $2 WHERE id = $3")
+        .bind(preview)
+        .bind(compressed)
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Returns the full content for a memory, decompressing
+/// `content_compressed` if present, otherwise falling back to `content`.
+pub async fn load_full_content(pool: &PgPool, id: Uuid) -> Result<String, CompressionError> {
+    let (content, compressed): (String, Option<Vec<u8>>) =
+        sqlx::query_as("SELECT content, content_compressed FROM memories WHERE id = $1")
+            .bind(id)
+            .fetch_one(pool)
+            .await?;
+
+    match compressed {
+        Some(bytes) => {
+            let decoded = zstd::stream::decode_all(bytes.as_slice())?;
+            Ok(String::from_utf8_lossy(&decoded).into_owned())
+        }
+        None => Ok(content),
+    }
+}
@@ -0,0 +1,56 @@
+//! Consolidates memory: removes exact-duplicate entries so the store
+//! doesn't grow unbounded with redundant detail. Scheduled alongside the
+//! other cleanup passes by `memory::cleanup::spawn`.
+
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+pub struct ConsolidationConfig {
+    pub interval: Duration,
+    /// Memories older than this are eligible for consolidation.
+    pub min_age: chrono::Duration,
+}
+
+impl Default for ConsolidationConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(3600),
+            min_age: chrono::Duration::days(1),
+        }
+    }
+}
+
+/// Groups memories older than `config.min_age` by exact content-hash
+/// duplicates (per namespace) and collapses each group down to the most
+/// recently accessed row, whose importance is left unchanged; the other
+/// rows are deleted. Returns the number of rows removed. Only
+/// exact-content duplicates are merged; memories that are merely
+/// similar by embedding distance are left as they are.
+pub async fn consolidate_once(
+    pool: &PgPool,
+    config: &ConsolidationConfig,
+) -> Result<u64, sqlx::Error> {
+    let cutoff = chrono::Utc::now() - config.min_age;
+
+    let result = sqlx::query(
+        r#"
+        WITH duplicates AS (
+            SELECT id,
+                   row_number() OVER (
+                       PARTITION BY md5(content), namespace
+                       ORDER BY last_accessed_at DESC
+                   ) AS rnk
+            FROM memories
+            WHERE created_at < $1
+        )
+        DELETE FROM memories
+        WHERE id IN (SELECT id FROM duplicates WHERE rnk > 1)
+        "#,
+    )
+    .bind(cutoff)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
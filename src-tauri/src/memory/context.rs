@@ -0,0 +1,97 @@
+//! Structured, provenance-carrying results for feeding retrieved
+//! memories back into a prompt, rather than returning bare strings.
+
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::memory::search::{search_memories, MemorySearchResult};
+use crate::settings::AgentSettings;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextItem {
+    pub memory_id: Uuid,
+    pub content: String,
+    pub score: f32,
+    pub provenance: Provenance,
+}
+
+/// Where a score came from, so downstream consumers (and debugging UIs)
+/// can tell a lexical hit from a vector hit from a graph traversal.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum Provenance {
+    Lexical { rank: i64 },
+    Vector { rank: i64 },
+    Hybrid { lexical_rank: i64, vector_rank: i64 },
+    GraphTraversal { relation: String },
+}
+
+/// Runs `search_memories` and converts the results into `ContextItem`s
+/// ready to inject into a prompt, honoring `rag_similarity_threshold`
+/// and `rag_max_results` from `AgentSettings` rather than handing back
+/// every hit regardless of how weak the match was.
+pub async fn retrieve_context(
+    pool: &PgPool,
+    query: &str,
+    query_embedding: &[f32],
+    namespace: Option<&str>,
+    settings: &AgentSettings,
+) -> Result<Vec<ContextItem>, sqlx::Error> {
+    let results = search_memories(
+        pool,
+        query,
+        query_embedding,
+        namespace,
+        settings,
+        settings.rag_max_results,
+    )
+    .await?;
+
+    let items: Vec<ContextItem> = results
+        .into_iter()
+        .filter(|result| result.fused_score >= settings.rag_similarity_threshold)
+        .map(ContextItem::from)
+        .collect();
+
+    audit_retrieval(pool, query, namespace, &items).await?;
+    Ok(items)
+}
+
+/// Records that `query` returned these memory ids, so a later review
+/// can answer "what did the assistant know when it said X" without
+/// relying on application logs.
+async fn audit_retrieval(
+    pool: &PgPool,
+    query: &str,
+    namespace: Option<&str>,
+    items: &[ContextItem],
+) -> Result<(), sqlx::Error> {
+    let result_ids: Vec<Uuid> = items.iter().map(|item| item.memory_id).collect();
+    sqlx::query(
+        "INSERT INTO memory_retrieval_audit (query, namespace, result_ids) VALUES ($1, $2, $3)",
+    )
+    .bind(query)
+    .bind(namespace)
+    .bind(result_ids)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+impl From<MemorySearchResult> for ContextItem {
+    fn from(result: MemorySearchResult) -> Self {
+        let provenance = match (result.lexical_rank, result.vector_rank) {
+            (Some(lexical_rank), Some(vector_rank)) => Provenance::Hybrid { lexical_rank, vector_rank },
+            (Some(rank), None) => Provenance::Lexical { rank },
+            (None, Some(rank)) => Provenance::Vector { rank },
+            (None, None) => Provenance::Vector { rank: 0 },
+        };
+        Self {
+            memory_id: result.id,
+            content: result.content,
+            score: result.fused_score,
+            provenance,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Per-memory TTL. Most memories never expire (`expires_at` is `NULL`),
+//! but callers that store short-lived context (e.g. a scratchpad fact
+//! for the current task) can set an expiry at write time.
+
+use sqlx::PgPool;
+
+/// Deletes all memories whose `expires_at` has passed. Returns the
+/// number of rows removed. Intended to be called from the same
+/// scheduled cleanup task that runs consolidation.
+pub async fn purge_expired(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM memories WHERE expires_at IS NOT NULL AND expires_at <= now()")
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
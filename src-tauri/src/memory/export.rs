@@ -0,0 +1,102 @@
+//! Export and import memories as JSONL, one memory per line, for backup,
+//! migration between environments, or manual inspection.
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::memory::store::{store_memories_batch, NewMemory};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportedMemory {
+    pub id: Uuid,
+    pub content: String,
+    pub embedding: Option<Vec<f32>>,
+    pub memory_type: String,
+    pub namespace: Option<String>,
+    pub tags: Vec<String>,
+    pub metadata: serde_json::Value,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Loads every (non-expired) memory and returns them as one JSONL
+/// string, oldest first.
+pub async fn export_jsonl(pool: &PgPool) -> Result<String, sqlx::Error> {
+    let rows: Vec<ExportedMemory> = sqlx::query_as::<_, ExportedMemoryRow>(
+        r#"
+        SELECT id, content, memory_type, namespace, tags, metadata, created_at,
+               embedding::text AS embedding_text
+        FROM memories
+        WHERE expires_at IS NULL OR expires_at > now()
+        ORDER BY created_at
+        "#,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(Into::into)
+    .collect();
+
+    let mut out = String::new();
+    for memory in rows {
+        out.push_str(&serde_json::to_string(&memory).unwrap_or_default());
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[derive(sqlx::FromRow)]
+struct ExportedMemoryRow {
+    id: Uuid,
+    content: String,
+    memory_type: String,
+    namespace: Option<String>,
+    tags: Vec<String>,
+    metadata: serde_json::Value,
+    created_at: chrono::DateTime<chrono::Utc>,
+    embedding_text: Option<String>,
+}
+
+impl From<ExportedMemoryRow> for ExportedMemory {
+    fn from(row: ExportedMemoryRow) -> Self {
+        let embedding = row.embedding_text.map(|text| {
+            text.trim_matches(|c| c == '[' || c == ']')
+                .split(',')
+                .filter_map(|v| v.parse::<f32>().ok())
+                .collect()
+        });
+        Self {
+            id: row.id,
+            content: row.content,
+            embedding,
+            memory_type: row.memory_type,
+            namespace: row.namespace,
+            tags: row.tags,
+            metadata: row.metadata,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// Parses JSONL produced by `export_jsonl` and inserts every memory in
+/// one transaction. Ids are not preserved (they're regenerated) so the
+/// same export can be imported into multiple environments without
+/// primary key collisions.
+pub async fn import_jsonl(pool: &PgPool, jsonl: &str) -> Result<Vec<Uuid>, sqlx::Error> {
+    let memories: Vec<NewMemory> = jsonl
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str::<ExportedMemory>(line).ok())
+        .map(|memory| NewMemory {
+            content: memory.content,
+            embedding: memory.embedding,
+            memory_type: memory.memory_type,
+            namespace: memory.namespace,
+            tags: memory.tags,
+            metadata: memory.metadata,
+            expires_at: None,
+        })
+        .collect();
+
+    store_memories_batch(pool, memories).await
+}
@@ -0,0 +1,100 @@
+//! Entity/relationship extraction into a lightweight memory graph, so
+//! retrieval can traverse "who/what is connected to this" rather than
+//! relying purely on similarity search.
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entity {
+    pub id: Uuid,
+    pub name: String,
+    pub entity_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Relationship {
+    pub source_entity_id: Uuid,
+    pub target_entity_id: Uuid,
+    pub relation: String,
+    pub memory_id: Uuid,
+}
+
+/// Upserts an entity by (name, entity_type) and returns its id.
+pub async fn upsert_entity(
+    pool: &PgPool,
+    name: &str,
+    entity_type: &str,
+) -> Result<Uuid, sqlx::Error> {
+    let (id,): (Uuid,) = sqlx::query_as(
+        r#"
+        INSERT INTO memory_entities (name, entity_type)
+        VALUES ($1, $2)
+        ON CONFLICT (name, entity_type) DO UPDATE SET name = EXCLUDED.name
+        RETURNING id
+        "#,
+    )
+    .bind(name)
+    .bind(entity_type)
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+/// Records a relationship extracted from `memory_id` linking two
+/// entities, e.g. ("Alice", "works_at", "Acme").
+pub async fn link_entities(
+    pool: &PgPool,
+    source_entity_id: Uuid,
+    target_entity_id: Uuid,
+    relation: &str,
+    memory_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO memory_relationships (source_entity_id, target_entity_id, relation, memory_id)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(source_entity_id)
+    .bind(target_entity_id)
+    .bind(relation)
+    .bind(memory_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Returns every entity directly connected to `entity_id`, along with
+/// the relation and the memory it was extracted from.
+pub async fn neighbors(
+    pool: &PgPool,
+    entity_id: Uuid,
+) -> Result<Vec<(Entity, Relationship)>, sqlx::Error> {
+    let rows: Vec<(Uuid, String, String, Uuid, Uuid, String, Uuid)> = sqlx::query_as(
+        r#"
+        SELECT e.id, e.name, e.entity_type, r.source_entity_id, r.target_entity_id, r.relation, r.memory_id
+        FROM memory_relationships r
+        JOIN memory_entities e ON e.id = CASE
+            WHEN r.source_entity_id = $1 THEN r.target_entity_id
+            ELSE r.source_entity_id
+        END
+        WHERE r.source_entity_id = $1 OR r.target_entity_id = $1
+        "#,
+    )
+    .bind(entity_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(id, name, entity_type, source, target, relation, memory_id)| {
+            (
+                Entity { id, name, entity_type },
+                Relationship { source_entity_id: source, target_entity_id: target, relation, memory_id },
+            )
+        })
+        .collect())
+}
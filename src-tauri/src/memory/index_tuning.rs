@@ -0,0 +1,14 @@
+//! Query-time tuning knobs for the optional HNSW embedding index
+//! (see `migrations/0003_hnsw_index.sql`).
+
+use sqlx::PgPool;
+
+/// Sets `hnsw.ef_search` for the current connection. Higher values trade
+/// query latency for recall; callers doing bulk re-embedding or batch
+/// search should raise this above the pgvector default of 40.
+pub async fn set_ef_search(pool: &PgPool, ef_search: u32) -> Result<(), sqlx::Error> {
+    sqlx::query(&format!("SET hnsw.ef_search = {ef_search}"))
+        .execute(pool)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! Ingests documents (PDF, Markdown, plain text) into memory as
+//! `Knowledge`-typed entries, chunked and embedded via the shared
+//! chunking pipeline.
+
+use std::path::Path;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::memory::chunking::{chunk_content, ChunkingConfig};
+use crate::memory::reembed::Embedder;
+use crate::memory::store::{store_memory, NewMemory};
+
+pub const KNOWLEDGE_MEMORY_TYPE: &str = "knowledge";
+
+#[derive(Debug, thiserror::Error)]
+pub enum IngestionError {
+    #[error("unsupported document extension: {0}")]
+    UnsupportedExtension(String),
+    #[error("failed to read document: {0}")]
+    Read(#[from] std::io::Error),
+    #[error("failed to extract PDF text: {0}")]
+    Pdf(String),
+    #[error(transparent)]
+    Embed(#[from] anyhow::Error),
+    #[error(transparent)]
+    Db(#[from] sqlx::Error),
+}
+
+/// Extracts plain text from a supported document based on its
+/// extension.
+pub fn extract_text(path: &Path) -> Result<String, IngestionError> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("txt") | Some("md") | Some("markdown") => Ok(std::fs::read_to_string(path)?),
+        Some("pdf") => pdf_extract::extract_text(path).map_err(|e| IngestionError::Pdf(e.to_string())),
+        other => Err(IngestionError::UnsupportedExtension(
+            other.unwrap_or("").to_string(),
+        )),
+    }
+}
+
+/// Reads, chunks and embeds `path`, storing each chunk as a Knowledge
+/// memory tagged with the source filename. Returns the ids of the
+/// memories created.
+pub async fn ingest_document(
+    pool: &PgPool,
+    path: &Path,
+    embedder: &dyn Embedder,
+    namespace: Option<&str>,
+) -> Result<Vec<Uuid>, IngestionError> {
+    let text = extract_text(path)?;
+    let chunks = chunk_content(&text, &ChunkingConfig::default());
+    let source = path.file_name().and_then(|n| n.to_str()).unwrap_or("document").to_string();
+
+    let mut ids = Vec::with_capacity(chunks.len());
+    for chunk in chunks {
+        let embedding = embedder.embed(&chunk.text).await?;
+        let id = store_memory(
+            pool,
+            NewMemory {
+                content: chunk.text,
+                embedding: Some(embedding),
+                memory_type: KNOWLEDGE_MEMORY_TYPE.to_string(),
+                namespace: namespace.map(str::to_string),
+                tags: vec![format!("source:{source}"), format!("chunk:{}", chunk.index)],
+                metadata: serde_json::json!({ "source": source, "chunk_index": chunk.index }),
+                expires_at: None,
+            },
+        )
+        .await?;
+        ids.push(id);
+    }
+
+    Ok(ids)
+}
@@ -0,0 +1,50 @@
+//! Merges two memories that turned out to describe the same thing
+//! (caught manually, or surfaced by consolidation/near-duplicate
+//! detection below the auto-merge threshold) into one, preserving
+//! revision history of both.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Merges `source` into `target`: `merged_content` becomes `target`'s
+/// new content, with its prior content snapshotted into
+/// `memory_revisions` first, `source`'s tags are unioned in, and
+/// `source` is soft-deleted rather than hard-deleted so the merge can be
+/// undone. Runs entirely in one transaction.
+pub async fn merge_memories(
+    pool: &PgPool,
+    target: Uuid,
+    source: Uuid,
+    merged_content: String,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "INSERT INTO memory_revisions (memory_id, content, metadata) SELECT id, content, metadata FROM memories WHERE id = $1",
+    )
+    .bind(target)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE memories
+        SET content = $2,
+            updated_at = now(),
+            tags = (SELECT array_agg(DISTINCT t) FROM unnest(tags || (SELECT tags FROM memories WHERE id = $3)) AS t)
+        WHERE id = $1
+        "#,
+    )
+    .bind(target)
+    .bind(merged_content)
+    .bind(source)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("UPDATE memories SET deleted_at = now() WHERE id = $1")
+        .bind(source)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await
+}
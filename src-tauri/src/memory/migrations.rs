@@ -0,0 +1,36 @@
+//! Versioned schema migrations for the memory store.
+//!
+//! Replaces the old approach of running a hand-written `setup_database`
+//! DDL block on every startup. Migrations live under `memory/migrations`
+//! and are embedded into the binary at compile time, so a given build is
+//! always paired with a known-good schema history.
+
+use sqlx::migrate::{MigrateError, Migrator};
+use sqlx::PgPool;
+
+static MIGRATOR: Migrator = sqlx::migrate!("src/memory/migrations");
+
+#[derive(Debug, thiserror::Error)]
+pub enum MigrationError {
+    #[error("failed to apply memory schema migrations: {0}")]
+    Apply(#[from] MigrateError),
+    #[error("failed to query schema version: {0}")]
+    Query(#[from] sqlx::Error),
+}
+
+/// Runs any pending migrations against `pool`. Safe to call on every
+/// startup: already-applied migrations are skipped.
+pub async fn run_migrations(pool: &PgPool) -> Result<(), MigrationError> {
+    MIGRATOR.run(pool).await?;
+    Ok(())
+}
+
+/// Returns the version of the most recently applied migration, or `None`
+/// if the migration table hasn't been created yet.
+pub async fn schema_version(pool: &PgPool) -> Result<Option<i64>, MigrationError> {
+    let row: Option<(i64,)> =
+        sqlx::query_as("SELECT version FROM _sqlx_migrations ORDER BY version DESC LIMIT 1")
+            .fetch_optional(pool)
+            .await?;
+    Ok(row.map(|(version,)| version))
+}
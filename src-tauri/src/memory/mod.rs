@@ -0,0 +1,96 @@
+//! Long-term memory subsystem: persistence, retrieval and maintenance of
+//! the assistant's memory store.
+
+pub mod backup;
+pub mod chunking;
+pub mod cleanup;
+pub mod compression;
+pub mod consolidation;
+pub mod context;
+pub mod expiry;
+pub mod export;
+pub mod graph;
+pub mod index_tuning;
+pub mod ingestion;
+pub mod merge;
+pub mod migrations;
+pub mod preferences;
+pub mod privacy;
+pub mod query_builder;
+pub mod ranking;
+pub mod reembed;
+pub mod related;
+pub mod rerank;
+pub mod revisions;
+pub mod search;
+pub mod store;
+pub mod streaming;
+pub mod trash;
+
+use std::time::Duration;
+
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+
+/// Handle to the memory store's connection pool. `connect` runs
+/// `migrations::run_migrations` before returning it; `run` does not
+/// create one and builds its own lazily connected pool instead.
+pub struct MemoryStore {
+    pool: PgPool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub acquire_timeout: Duration,
+    pub idle_timeout: Option<Duration>,
+    /// Postgres `statement_timeout`, applied to every connection on
+    /// checkout so a runaway query can't hold the pool hostage.
+    pub statement_timeout: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: 10,
+            min_connections: 1,
+            acquire_timeout: Duration::from_secs(10),
+            idle_timeout: Some(Duration::from_secs(600)),
+            statement_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl MemoryStore {
+    /// Connects to `database_url` using `config`'s pool and statement
+    /// timeout settings, then applies any pending migrations.
+    pub async fn connect(
+        database_url: &str,
+        config: &PoolConfig,
+    ) -> Result<Self, migrations::MigrationError> {
+        let statement_timeout_ms = config.statement_timeout.as_millis();
+        let pool = PgPoolOptions::new()
+            .max_connections(config.max_connections)
+            .min_connections(config.min_connections)
+            .acquire_timeout(config.acquire_timeout)
+            .idle_timeout(config.idle_timeout)
+            .after_connect(move |conn, _meta| {
+                Box::pin(async move {
+                    sqlx::query(&format!("SET statement_timeout = {statement_timeout_ms}"))
+                        .execute(conn)
+                        .await?;
+                    Ok(())
+                })
+            })
+            .connect(database_url)
+            .await
+            .map_err(migrations::MigrationError::Query)?;
+        migrations::run_migrations(&pool).await?;
+        Ok(Self { pool })
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
@@ -0,0 +1,62 @@
+//! Extracts durable user preferences ("prefers dark mode", "hates being
+//! interrupted mid-task") out of conversation turns and stores them as
+//! high-importance Preference memories, so they survive far longer than
+//! the conversation that revealed them.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::memory::reembed::Embedder;
+use crate::memory::store::{store_memory, NewMemory};
+
+pub const PREFERENCE_MEMORY_TYPE: &str = "preference";
+/// Preferences outlive the conversation that revealed them, so they're
+/// stored above the default importance (0.5) to resist time-decay.
+const PREFERENCE_IMPORTANCE: f32 = 0.8;
+
+/// A caller-supplied extractor (typically an LLM prompted to pull
+/// preference statements out of a turn), kept separate from storage so
+/// this module doesn't depend on a specific model or prompt.
+#[async_trait::async_trait]
+pub trait PreferenceExtractor {
+    async fn extract(&self, conversation_turn: &str) -> anyhow::Result<Vec<String>>;
+}
+
+/// Runs `extractor` over `conversation_turn` and stores each extracted
+/// preference as a Preference memory (near-duplicate detection in
+/// `store_memory` naturally dedupes a preference restated across turns).
+pub async fn extract_and_store(
+    pool: &PgPool,
+    conversation_turn: &str,
+    extractor: &dyn PreferenceExtractor,
+    embedder: &dyn Embedder,
+    namespace: Option<&str>,
+) -> anyhow::Result<Vec<Uuid>> {
+    let preferences = extractor.extract(conversation_turn).await?;
+    let mut ids = Vec::with_capacity(preferences.len());
+
+    for preference in preferences {
+        let embedding = embedder.embed(&preference).await?;
+        let id = store_memory(
+            pool,
+            NewMemory {
+                content: preference,
+                embedding: Some(embedding),
+                memory_type: PREFERENCE_MEMORY_TYPE.to_string(),
+                namespace: namespace.map(str::to_string),
+                tags: vec!["auto-extracted".to_string()],
+                metadata: serde_json::json!({}),
+                expires_at: None,
+            },
+        )
+        .await?;
+        sqlx::query("UPDATE memories SET importance = $1 WHERE id = $2")
+            .bind(PREFERENCE_IMPORTANCE)
+            .bind(id)
+            .execute(pool)
+            .await?;
+        ids.push(id);
+    }
+
+    Ok(ids)
+}
@@ -0,0 +1,46 @@
+//! "Forget everything about X": a hard, unrecoverable wipe, distinct
+//! from `trash::soft_delete`, for privacy requests where a soft-deleted
+//! row sitting in trash for `TRASH_RETENTION` isn't good enough.
+
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Finds every memory that's a lexical or vector match for `subject`,
+/// permanently deletes them (cascading into revisions and relationships
+/// via their memory_id foreign keys; entities have no memory_id and are
+/// left in place), and returns the
+/// number of rows removed. This does not go through trash — it's meant
+/// to satisfy "forget X" requests, not accidental deletes.
+pub async fn forget_everything_about(
+    pool: &PgPool,
+    subject: &str,
+    query_embedding: &[f32],
+) -> Result<u64, sqlx::Error> {
+    let embedding = pgvector::Vector::from(query_embedding.to_vec());
+
+    let ids: Vec<(Uuid,)> = sqlx::query_as(
+        r#"
+        SELECT id FROM memories
+        WHERE content_tsv @@ plainto_tsquery('english', $1)
+           OR embedding <=> $2 < 0.2
+        "#,
+    )
+    .bind(subject)
+    .bind(embedding)
+    .fetch_all(pool)
+    .await?;
+
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    let ids: Vec<Uuid> = ids.into_iter().map(|(id,)| id).collect();
+    let result = sqlx::query("DELETE FROM memories WHERE id = ANY($1)")
+        .bind(&ids)
+        .execute(pool)
+        .await?;
+
+    warn!(subject, removed = result.rows_affected(), "privacy wipe executed");
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,95 @@
+//! Builds parameterized `WHERE` clauses for filtered memory search so
+//! callers can compose filters (type, namespace, tags, date range)
+//! without ever interpolating user input into SQL text.
+
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, QueryBuilder};
+
+#[derive(Debug, Default, Clone)]
+pub struct MemoryFilter {
+    pub memory_types: Vec<String>,
+    pub namespace: Option<String>,
+    pub tags: Vec<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl MemoryFilter {
+    /// Appends this filter's conditions to an existing `QueryBuilder`,
+    /// starting with `AND` since it's meant to be chained after a base
+    /// `WHERE` clause. All values are bound, never interpolated.
+    pub fn push_conditions<'a>(&'a self, qb: &mut QueryBuilder<'a, Postgres>) {
+        if !self.memory_types.is_empty() {
+            qb.push(" AND memory_type = ANY(");
+            qb.push_bind(&self.memory_types);
+            qb.push(")");
+        }
+        if let Some(namespace) = &self.namespace {
+            qb.push(" AND namespace = ");
+            qb.push_bind(namespace);
+        }
+        if !self.tags.is_empty() {
+            qb.push(" AND tags @> ");
+            qb.push_bind(&self.tags);
+        }
+        if let Some(after) = self.created_after {
+            qb.push(" AND created_at >= ");
+            qb.push_bind(after);
+        }
+        if let Some(before) = self.created_before {
+            qb.push(" AND created_at <= ");
+            qb.push_bind(before);
+        }
+    }
+}
+
+/// Builds a full filtered search query, ready to `.build_query_as()`.
+pub fn build_filtered_search<'a>(filter: &'a MemoryFilter, limit: i64) -> QueryBuilder<'a, Postgres> {
+    let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+        "SELECT id, content, memory_type, namespace, tags, created_at FROM memories WHERE deleted_at IS NULL AND (expires_at IS NULL OR expires_at > now())",
+    );
+    filter.push_conditions(&mut qb);
+    qb.push(" ORDER BY created_at DESC LIMIT ");
+    qb.push_bind(limit);
+    qb
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_empty_filter_adds_no_conditions() {
+        let filter = MemoryFilter::default();
+        let qb = build_filtered_search(&filter, 50);
+        let sql = qb.sql();
+        assert!(!sql.contains("memory_type"));
+        assert!(!sql.contains("namespace ="));
+        assert!(sql.contains("ORDER BY created_at DESC LIMIT"));
+    }
+
+    #[test]
+    fn each_populated_field_appends_its_own_bound_condition() {
+        let filter = MemoryFilter {
+            memory_types: vec!["note".to_string()],
+            namespace: Some("work".to_string()),
+            tags: vec!["urgent".to_string()],
+            created_after: Some(Utc::now()),
+            created_before: Some(Utc::now()),
+        };
+        let qb = build_filtered_search(&filter, 10);
+        let sql = qb.sql();
+        assert!(sql.contains("memory_type = ANY("));
+        assert!(sql.contains("namespace = "));
+        assert!(sql.contains("tags @> "));
+        assert!(sql.contains("created_at >= "));
+        assert!(sql.contains("created_at <= "));
+    }
+
+    #[test]
+    fn filter_values_are_bound_rather_than_interpolated() {
+        let filter = MemoryFilter { namespace: Some("'; DROP TABLE memories; --".to_string()), ..Default::default() };
+        let qb = build_filtered_search(&filter, 10);
+        assert!(!qb.sql().contains("DROP TABLE"));
+    }
+}
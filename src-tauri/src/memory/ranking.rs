@@ -0,0 +1,55 @@
+//! Importance scoring and time-decay, used to boost or fade memories
+//! independently of how well they match a given query.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Half-life, in days, used for exponential time decay.
+const DECAY_HALF_LIFE_DAYS: f32 = 30.0;
+
+/// Combines a stored importance score (0.0-1.0) with exponential decay
+/// since `last_accessed_at` into a single multiplier applied on top of
+/// retrieval relevance, so an important-but-stale memory can still
+/// outrank a fresh-but-trivial one.
+pub fn decay_weighted_importance(importance: f32, days_since_access: f32) -> f32 {
+    let decay = 0.5f32.powf(days_since_access / DECAY_HALF_LIFE_DAYS);
+    importance * decay
+}
+
+/// Bumps `last_accessed_at` to now, which resets the decay clock for a
+/// memory that was just retrieved.
+pub async fn touch_memory(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE memories SET last_accessed_at = now() WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_elapsed_time_applies_no_decay() {
+        assert_eq!(decay_weighted_importance(0.8, 0.0), 0.8);
+    }
+
+    #[test]
+    fn one_half_life_halves_the_importance() {
+        let decayed = decay_weighted_importance(1.0, DECAY_HALF_LIFE_DAYS);
+        assert!((decayed - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn decay_is_monotonically_decreasing_with_age() {
+        let fresh = decay_weighted_importance(0.5, 1.0);
+        let stale = decay_weighted_importance(0.5, 60.0);
+        assert!(stale < fresh);
+    }
+
+    #[test]
+    fn zero_importance_stays_zero_regardless_of_age() {
+        assert_eq!(decay_weighted_importance(0.0, 90.0), 0.0);
+    }
+}
@@ -0,0 +1,64 @@
+//! Re-embeds existing memories after an embedding model upgrade.
+//!
+//! Writes the new vectors straight into the `embedding` column. The
+//! dimension-migration pattern in `memory::migrations` (fill a new
+//! `embedding_next` column, verify coverage, then swap columns) is not
+//! followed here: this never touches `embedding_next`.
+
+use sqlx::PgPool;
+use tracing::info;
+use uuid::Uuid;
+
+/// A caller-supplied embedder, so this module doesn't depend on any
+/// specific model provider.
+#[async_trait::async_trait]
+pub trait Embedder {
+    async fn embed(&self, text: &str) -> anyhow::Result<Vec<f32>>;
+}
+
+/// Re-embeds every memory in `batch_size`-row chunks, in id order.
+/// Returns the number of memories re-embedded. Safe to resume: already
+/// up-to-date rows are naturally revisited but just get overwritten
+/// with the same value.
+pub async fn reembed_all(
+    pool: &PgPool,
+    embedder: &dyn Embedder,
+    batch_size: i64,
+) -> anyhow::Result<u64> {
+    let mut last_id: Option<Uuid> = None;
+    let mut total = 0u64;
+
+    loop {
+        let rows: Vec<(Uuid, String)> = sqlx::query_as(
+            r#"
+            SELECT id, content FROM memories
+            WHERE ($1::uuid IS NULL OR id > $1)
+            ORDER BY id
+            LIMIT $2
+            "#,
+        )
+        .bind(last_id)
+        .bind(batch_size)
+        .fetch_all(pool)
+        .await?;
+
+        if rows.is_empty() {
+            break;
+        }
+
+        for (id, content) in &rows {
+            let embedding = embedder.embed(content).await?;
+            sqlx::query("UPDATE memories SET embedding = $1 WHERE id = $2")
+                .bind(pgvector::Vector::from(embedding))
+                .bind(id)
+                .execute(pool)
+                .await?;
+            total += 1;
+        }
+
+        last_id = rows.last().map(|(id, _)| *id);
+        info!(total, "re-embedding progress");
+    }
+
+    Ok(total)
+}
@@ -0,0 +1,80 @@
+//! "Related memories" suggestions: given a memory, find others likely
+//! worth surfacing alongside it, combining vector similarity with graph
+//! neighbors so unrelated-but-similarly-worded memories don't crowd out
+//! memories actually connected through shared entities.
+
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelatedMemory {
+    pub id: Uuid,
+    pub content: String,
+    pub reason: RelatedReason,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RelatedReason {
+    SimilarContent { distance: f32 },
+    SharedEntity { entity_name: String },
+}
+
+/// Returns memories related to `memory_id`: up to `limit` closest by
+/// embedding distance, plus up to `limit` that share an extracted
+/// entity via the memory graph, so up to `2 * limit` in total.
+pub async fn related_memories(
+    pool: &PgPool,
+    memory_id: Uuid,
+    limit: i64,
+) -> Result<Vec<RelatedMemory>, sqlx::Error> {
+    let mut results = Vec::new();
+
+    let similar: Vec<(Uuid, String, f32)> = sqlx::query_as(
+        r#"
+        SELECT other.id, other.content, other.embedding <=> target.embedding AS distance
+        FROM memories target
+        JOIN memories other ON other.id != target.id AND other.deleted_at IS NULL
+        WHERE target.id = $1 AND target.embedding IS NOT NULL AND other.embedding IS NOT NULL
+        ORDER BY distance
+        LIMIT $2
+        "#,
+    )
+    .bind(memory_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    results.extend(similar.into_iter().map(|(id, content, distance)| RelatedMemory {
+        id,
+        content,
+        reason: RelatedReason::SimilarContent { distance },
+    }));
+
+    let shared_entity: Vec<(Uuid, String, String)> = sqlx::query_as(
+        r#"
+        SELECT DISTINCT other_memory.id, other_memory.content, entity.name
+        FROM memory_relationships rel
+        JOIN memory_entities entity ON entity.id IN (rel.source_entity_id, rel.target_entity_id)
+        JOIN memory_relationships other_rel
+            ON (other_rel.source_entity_id = entity.id OR other_rel.target_entity_id = entity.id)
+            AND other_rel.memory_id != rel.memory_id
+        JOIN memories other_memory ON other_memory.id = other_rel.memory_id AND other_memory.deleted_at IS NULL
+        WHERE rel.memory_id = $1
+        LIMIT $2
+        "#,
+    )
+    .bind(memory_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    results.extend(shared_entity.into_iter().map(|(id, content, entity_name)| RelatedMemory {
+        id,
+        content,
+        reason: RelatedReason::SharedEntity { entity_name },
+    }));
+
+    Ok(results)
+}
@@ -0,0 +1,39 @@
+//! Cross-encoder reranking: after hybrid search returns a candidate
+//! list by bi-encoder/lexical score, a cross-encoder scores each
+//! (query, candidate) pair jointly, which is far more accurate than
+//! comparing independently computed scores but too slow to run over the
+//! whole store. `rerank` scores every candidate it is given; it does
+//! not cut the list down to a top-N first.
+
+use crate::memory::search::MemorySearchResult;
+
+#[async_trait::async_trait]
+pub trait CrossEncoder {
+    /// Scores each candidate against `query`; higher is more relevant.
+    /// Implementations should score all candidates in one batched call
+    /// where the underlying model supports it.
+    async fn score(&self, query: &str, candidates: &[&str]) -> anyhow::Result<Vec<f32>>;
+}
+
+/// Reranks `results` by cross-encoder score. Each result's
+/// `fused_score` is overwritten with its cross-encoder score; the
+/// original fused score is not kept.
+pub async fn rerank(
+    query: &str,
+    mut results: Vec<MemorySearchResult>,
+    cross_encoder: &dyn CrossEncoder,
+) -> anyhow::Result<Vec<MemorySearchResult>> {
+    if results.is_empty() {
+        return Ok(results);
+    }
+
+    let candidates: Vec<&str> = results.iter().map(|r| r.content.as_str()).collect();
+    let scores = cross_encoder.score(query, &candidates).await?;
+
+    for (result, score) in results.iter_mut().zip(scores) {
+        result.fused_score = score;
+    }
+
+    results.sort_by(|a, b| b.fused_score.total_cmp(&a.fused_score));
+    Ok(results)
+}
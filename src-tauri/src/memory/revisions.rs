@@ -0,0 +1,53 @@
+//! Revision history for edited memories: every update to `content` or
+//! `metadata` snapshots the prior value before overwriting it.
+
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Debug, sqlx::FromRow, serde::Serialize)]
+pub struct MemoryRevision {
+    pub id: Uuid,
+    pub content: String,
+    pub metadata: JsonValue,
+    pub revised_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Snapshots the current content/metadata into `memory_revisions`, then
+/// updates the memory in place. Runs both in one transaction.
+pub async fn update_memory(
+    pool: &PgPool,
+    id: Uuid,
+    content: String,
+    metadata: JsonValue,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO memory_revisions (memory_id, content, metadata)
+        SELECT id, content, metadata FROM memories WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("UPDATE memories SET content = $1, metadata = $2, updated_at = now() WHERE id = $3")
+        .bind(content)
+        .bind(metadata)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await
+}
+
+pub async fn history(pool: &PgPool, memory_id: Uuid) -> Result<Vec<MemoryRevision>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT id, content, metadata, revised_at FROM memory_revisions WHERE memory_id = $1 ORDER BY revised_at DESC",
+    )
+    .bind(memory_id)
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,160 @@
+//! Hybrid lexical + vector retrieval over the memory store.
+//!
+//! Pure cosine similarity misses exact names, identifiers and codes that
+//! a lexical match would catch immediately, so `search_memories` runs
+//! both queries and fuses their rankings with Reciprocal Rank Fusion
+//! (RRF) rather than trying to normalize and blend raw scores, which
+//! behave very differently between the two retrieval methods.
+
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::memory::ranking::decay_weighted_importance;
+use crate::settings::AgentSettings;
+
+const RRF_K: f32 = 60.0;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemorySearchResult {
+    pub id: Uuid,
+    pub content: String,
+    pub lexical_rank: Option<i64>,
+    pub vector_rank: Option<i64>,
+    pub fused_score: f32,
+}
+
+#[derive(sqlx::FromRow)]
+struct RankedRow {
+    id: Uuid,
+    content: String,
+    lexical_rank: Option<i64>,
+    vector_rank: Option<i64>,
+    importance: f32,
+    days_since_access: f32,
+    days_since_creation: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    pub created_after: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_before: Option<chrono::DateTime<chrono::Utc>>,
+    /// Extra weight applied to recency on top of importance decay, for
+    /// callers that want "what have we discussed lately" rather than
+    /// pure relevance (0.0 disables it).
+    pub recency_weight: f32,
+}
+
+pub async fn search_memories(
+    pool: &PgPool,
+    query: &str,
+    query_embedding: &[f32],
+    namespace: Option<&str>,
+    settings: &AgentSettings,
+    limit: i64,
+) -> Result<Vec<MemorySearchResult>, sqlx::Error> {
+    search_memories_with_options(
+        pool,
+        query,
+        query_embedding,
+        namespace,
+        settings,
+        limit,
+        &SearchOptions::default(),
+    )
+    .await
+}
+
+/// Runs a lexical (`plainto_tsquery`) and a vector (cosine distance)
+/// search in parallel result sets, then fuses them with RRF:
+/// `weight / (k + rank)` summed across whichever lists a memory appears
+/// in, scaled by `0.5 + decay_weighted_importance(..)`, plus a recency
+/// boost of `options.recency_weight / (1 + days since creation)`.
+pub async fn search_memories_with_options(
+    pool: &PgPool,
+    query: &str,
+    query_embedding: &[f32],
+    namespace: Option<&str>,
+    settings: &AgentSettings,
+    limit: i64,
+    options: &SearchOptions,
+) -> Result<Vec<MemorySearchResult>, sqlx::Error> {
+    let embedding = pgvector::Vector::from(query_embedding.to_vec());
+
+    let rows: Vec<RankedRow> = sqlx::query_as(
+        r#"
+        WITH lexical AS (
+            SELECT id, content,
+                   row_number() OVER (ORDER BY ts_rank(content_tsv, plainto_tsquery('english', $1)) DESC) AS rnk
+            FROM memories
+            WHERE content_tsv @@ plainto_tsquery('english', $1)
+              AND ($4::text IS NULL OR namespace = $4)
+              AND (expires_at IS NULL OR expires_at > now())
+              AND deleted_at IS NULL
+              AND ($5::timestamptz IS NULL OR created_at >= $5)
+              AND ($6::timestamptz IS NULL OR created_at <= $6)
+            LIMIT $3
+        ),
+        vector AS (
+            SELECT id, content,
+                   row_number() OVER (ORDER BY embedding <=> $2) AS rnk
+            FROM memories
+            WHERE embedding IS NOT NULL
+              AND ($4::text IS NULL OR namespace = $4)
+              AND (expires_at IS NULL OR expires_at > now())
+              AND deleted_at IS NULL
+              AND ($5::timestamptz IS NULL OR created_at >= $5)
+              AND ($6::timestamptz IS NULL OR created_at <= $6)
+            LIMIT $3
+        )
+        SELECT
+            coalesce(lexical.id, vector.id) AS id,
+            coalesce(lexical.content, vector.content) AS content,
+            lexical.rnk AS lexical_rank,
+            vector.rnk AS vector_rank,
+            memories.importance AS importance,
+            EXTRACT(EPOCH FROM (now() - memories.last_accessed_at)) / 86400.0 AS days_since_access,
+            EXTRACT(EPOCH FROM (now() - memories.created_at)) / 86400.0 AS days_since_creation
+        FROM lexical
+        FULL OUTER JOIN vector ON lexical.id = vector.id
+        JOIN memories ON memories.id = coalesce(lexical.id, vector.id)
+        "#,
+    )
+    .bind(query)
+    .bind(embedding)
+    .bind(limit)
+    .bind(namespace)
+    .bind(options.created_after)
+    .bind(options.created_before)
+    .fetch_all(pool)
+    .await?;
+
+    let mut results: Vec<MemorySearchResult> = rows
+        .into_iter()
+        .map(|row| {
+            let lexical_score = row
+                .lexical_rank
+                .map(|rank| settings.lexical_search_weight / (RRF_K + rank as f32))
+                .unwrap_or(0.0);
+            let vector_score = row
+                .vector_rank
+                .map(|rank| settings.vector_search_weight / (RRF_K + rank as f32))
+                .unwrap_or(0.0);
+            let importance_weight =
+                decay_weighted_importance(row.importance, row.days_since_access);
+            let recency_boost = options.recency_weight / (1.0 + row.days_since_creation);
+            MemorySearchResult {
+                id: row.id,
+                content: row.content,
+                lexical_rank: row.lexical_rank,
+                vector_rank: row.vector_rank,
+                fused_score: (lexical_score + vector_score) * (0.5 + importance_weight)
+                    + recency_boost,
+            }
+        })
+        .collect();
+
+    results.sort_by(|a, b| b.fused_score.total_cmp(&a.fused_score));
+    results.truncate(limit as usize);
+    Ok(results)
+}
@@ -0,0 +1,150 @@
+//! Basic CRUD for memory entries.
+
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Cosine distance below which an incoming memory is considered a
+/// near-duplicate of an existing one (pgvector's `<=>` returns distance,
+/// so smaller is more similar).
+const NEAR_DUPLICATE_DISTANCE: f32 = 0.05;
+
+#[derive(Debug, Clone)]
+pub struct NewMemory {
+    pub content: String,
+    pub embedding: Option<Vec<f32>>,
+    pub memory_type: String,
+    pub namespace: Option<String>,
+    pub tags: Vec<String>,
+    pub metadata: JsonValue,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Inserts a new memory, unless it's a near-duplicate of an existing one
+/// (same namespace, cosine distance below `NEAR_DUPLICATE_DISTANCE`), in
+/// which case the existing memory's id is returned and touched instead
+/// of inserting a redundant row.
+pub async fn store_memory(pool: &PgPool, memory: NewMemory) -> Result<Uuid, sqlx::Error> {
+    if let Some(embedding) = &memory.embedding {
+        if let Some(existing_id) =
+            find_near_duplicate(pool, embedding, memory.namespace.as_deref()).await?
+        {
+            crate::memory::ranking::touch_memory(pool, existing_id).await?;
+            return Ok(existing_id);
+        }
+    }
+
+    let embedding = memory.embedding.map(pgvector::Vector::from);
+    let (id,): (Uuid,) = sqlx::query_as(
+        r#"
+        INSERT INTO memories (content, embedding, memory_type, namespace, tags, metadata, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#,
+    )
+    .bind(memory.content)
+    .bind(embedding)
+    .bind(memory.memory_type)
+    .bind(memory.namespace)
+    .bind(&memory.tags)
+    .bind(memory.metadata)
+    .bind(memory.expires_at)
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+/// Inserts many memories in a single transaction, so a failure partway
+/// through (e.g. a bad embedding dimension) leaves no partial batch
+/// behind. Near-duplicate detection still applies per-memory.
+pub async fn store_memories_batch(
+    pool: &PgPool,
+    memories: Vec<NewMemory>,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let mut ids = Vec::with_capacity(memories.len());
+
+    for memory in memories {
+        if let Some(embedding) = &memory.embedding {
+            if let Some(existing_id) =
+                find_near_duplicate(&mut *tx, embedding, memory.namespace.as_deref()).await?
+            {
+                ids.push(existing_id);
+                continue;
+            }
+        }
+
+        let embedding = memory.embedding.map(pgvector::Vector::from);
+        let (id,): (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO memories (content, embedding, memory_type, namespace, tags, metadata, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id
+            "#,
+        )
+        .bind(memory.content)
+        .bind(embedding)
+        .bind(memory.memory_type)
+        .bind(memory.namespace)
+        .bind(&memory.tags)
+        .bind(memory.metadata)
+        .bind(memory.expires_at)
+        .fetch_one(&mut *tx)
+        .await?;
+        ids.push(id);
+    }
+
+    tx.commit().await?;
+    Ok(ids)
+}
+
+/// Finds the closest existing memory within `NEAR_DUPLICATE_DISTANCE` of
+/// `embedding` in the same namespace, if any. Generic over the executor
+/// so it can run against either a pool or an in-flight transaction.
+async fn find_near_duplicate<'e, E>(
+    executor: E,
+    embedding: &[f32],
+    namespace: Option<&str>,
+) -> Result<Option<Uuid>, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    let embedding = pgvector::Vector::from(embedding.to_vec());
+    let row: Option<(Uuid,)> = sqlx::query_as(
+        r#"
+        SELECT id FROM memories
+        WHERE embedding IS NOT NULL
+          AND ($3::text IS NULL OR namespace = $3)
+          AND embedding <=> $1 < $2
+        ORDER BY embedding <=> $1
+        LIMIT 1
+        "#,
+    )
+    .bind(embedding)
+    .bind(NEAR_DUPLICATE_DISTANCE)
+    .bind(namespace)
+    .fetch_optional(executor)
+    .await?;
+    Ok(row.map(|(id,)| id))
+}
+
+/// Returns memories that carry every tag in `tags`, most recent first.
+pub async fn find_by_tags(
+    pool: &PgPool,
+    tags: &[String],
+    limit: i64,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let rows: Vec<(Uuid,)> = sqlx::query_as(
+        r#"
+        SELECT id FROM memories
+        WHERE tags @> $1
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(tags)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
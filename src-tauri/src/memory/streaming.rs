@@ -0,0 +1,25 @@
+//! Streams large memory scans (export, bulk re-read) row by row instead
+//! of materializing the whole result set, so a full-store scan doesn't
+//! blow up memory usage.
+
+use futures_util::Stream;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow, serde::Serialize)]
+pub struct MemoryRow {
+    pub id: Uuid,
+    pub content: String,
+    pub memory_type: String,
+    pub namespace: Option<String>,
+}
+
+/// Returns a lazy stream over every non-deleted memory, oldest first.
+/// Callers should process items as they arrive (e.g. write to a file or
+/// a channel) rather than collecting the whole stream into a `Vec`.
+pub fn scan_all(pool: &PgPool) -> impl Stream<Item = Result<MemoryRow, sqlx::Error>> + '_ {
+    sqlx::query_as::<_, MemoryRow>(
+        "SELECT id, content, memory_type, namespace FROM memories WHERE deleted_at IS NULL ORDER BY created_at",
+    )
+    .fetch(pool)
+}
@@ -0,0 +1,54 @@
+//! Soft delete: `delete_memory` marks a row deleted rather than removing
+//! it outright, so an accidental delete can be restored. Search and
+//! `get_memory_stats` exclude `deleted_at IS NOT NULL` rows through
+//! their own queries' `deleted_at IS NULL` filters; a separate
+//! hard-purge job removes trash past its retention window.
+
+use chrono::Duration;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// How long a soft-deleted memory stays restorable before being purged.
+pub const TRASH_RETENTION: Duration = Duration::days(30);
+
+pub async fn soft_delete(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE memories SET deleted_at = now() WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn restore(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE memories SET deleted_at = NULL WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, sqlx::FromRow, serde::Serialize)]
+pub struct TrashedMemory {
+    pub id: Uuid,
+    pub content: String,
+    pub deleted_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub async fn list_trash(pool: &PgPool) -> Result<Vec<TrashedMemory>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT id, content, deleted_at FROM memories WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Permanently removes trash older than `TRASH_RETENTION`. Returns the
+/// number of rows purged.
+pub async fn purge_trash(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let cutoff = chrono::Utc::now() - TRASH_RETENTION;
+    let result = sqlx::query("DELETE FROM memories WHERE deleted_at IS NOT NULL AND deleted_at <= $1")
+        .bind(cutoff)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,195 @@
+//! Admin code verification with failed-attempt lockout.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::security::keyring_store::{self, KeyringError};
+
+const ADMIN_CODE_HASH_KEY: &str = "admin_code_hash";
+
+/// The code shipped in documentation and first-run instructions. Still
+/// being in place after setup is a weak-config condition worth flagging
+/// on the security dashboard.
+pub const DEFAULT_ADMIN_CODE: &str = "change-me-immediately";
+
+const MAX_FAILED_ATTEMPTS: u32 = 5;
+const LOCKOUT_DURATION: Duration = Duration::from_secs(15 * 60);
+
+struct AttemptState {
+    failed_attempts: u32,
+    locked_until: Option<Instant>,
+    /// Set after each failure; the next attempt must wait until this
+    /// instant, with the delay doubling per consecutive failure (capped
+    /// below the hard lockout), so a fast brute-force loop slows to a
+    /// crawl well before it hits `MAX_FAILED_ATTEMPTS`.
+    next_attempt_allowed_at: Option<Instant>,
+}
+
+const BASE_DELAY: Duration = Duration::from_millis(500);
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Tracks failed admin-code attempts per session so a brute-force loop
+/// gets locked out instead of getting unlimited guesses.
+pub struct AdminAuth {
+    code_hash: RwLock<String>,
+    attempts: Mutex<HashMap<String, AttemptState>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AdminAuthError {
+    #[error("incorrect admin code")]
+    Incorrect,
+    #[error("too many failed attempts; locked out for {remaining_secs}s")]
+    LockedOut { remaining_secs: u64 },
+    #[error("current admin code is incorrect")]
+    RotationDenied,
+    #[error("failed to hash new admin code: {0}")]
+    Hash(#[from] bcrypt::BcryptError),
+    #[error(transparent)]
+    Keyring(#[from] KeyringError),
+}
+
+impl AdminAuth {
+    pub fn new(code_hash: String) -> Self {
+        Self { code_hash: RwLock::new(code_hash), attempts: Mutex::new(HashMap::new()) }
+    }
+
+    /// Loads the admin code hash from the OS keyring, falling back to
+    /// `default_hash` (e.g. on first run) if none is stored yet.
+    pub fn load_or_init(default_hash: String) -> Result<Self, AdminAuthError> {
+        let hash = keyring_store::get_secret(ADMIN_CODE_HASH_KEY)?.unwrap_or(default_hash);
+        Ok(Self::new(hash))
+    }
+
+    /// Rotates the admin code: verifies `current_code` against the
+    /// existing hash, then hashes and persists `new_code` to the
+    /// keyring. Resets failed-attempt tracking since the old code is no
+    /// longer valid input for it.
+    pub fn update_admin_code(
+        &self,
+        current_code: &str,
+        new_code: &str,
+    ) -> Result<(), AdminAuthError> {
+        let current_hash = self.code_hash.read().unwrap().clone();
+        if !bcrypt::verify(current_code, &current_hash).unwrap_or(false) {
+            return Err(AdminAuthError::RotationDenied);
+        }
+
+        let new_hash = bcrypt::hash(new_code, bcrypt::DEFAULT_COST)?;
+        keyring_store::set_secret(ADMIN_CODE_HASH_KEY, &new_hash)?;
+        *self.code_hash.write().unwrap() = new_hash;
+        self.attempts.lock().unwrap().clear();
+        Ok(())
+    }
+
+    /// Verifies `code` against the stored hash for `session_id`. Locks
+    /// the session out for `LOCKOUT_DURATION` after `MAX_FAILED_ATTEMPTS`
+    /// consecutive failures; a correct code resets the counter.
+    pub fn verify_admin_code(&self, session_id: &str, code: &str) -> Result<(), AdminAuthError> {
+        let mut attempts = self.attempts.lock().unwrap();
+        let state = attempts
+            .entry(session_id.to_string())
+            .or_insert(AttemptState { failed_attempts: 0, locked_until: None, next_attempt_allowed_at: None });
+
+        if let Some(locked_until) = state.locked_until {
+            if locked_until > Instant::now() {
+                let remaining_secs = (locked_until - Instant::now()).as_secs();
+                return Err(AdminAuthError::LockedOut { remaining_secs });
+            }
+            state.locked_until = None;
+            state.failed_attempts = 0;
+        }
+
+        if let Some(allowed_at) = state.next_attempt_allowed_at {
+            if allowed_at > Instant::now() {
+                let remaining_secs = (allowed_at - Instant::now()).as_secs();
+                return Err(AdminAuthError::LockedOut { remaining_secs });
+            }
+        }
+
+        let code_hash = self.code_hash.read().unwrap().clone();
+        if bcrypt::verify(code, &code_hash).unwrap_or(false) {
+            state.failed_attempts = 0;
+            state.next_attempt_allowed_at = None;
+            return Ok(());
+        }
+
+        state.failed_attempts += 1;
+        if state.failed_attempts >= MAX_FAILED_ATTEMPTS {
+            state.locked_until = Some(Instant::now() + LOCKOUT_DURATION);
+            return Err(AdminAuthError::LockedOut { remaining_secs: LOCKOUT_DURATION.as_secs() });
+        }
+
+        let delay = (BASE_DELAY * 2u32.pow(state.failed_attempts.saturating_sub(1))).min(MAX_DELAY);
+        state.next_attempt_allowed_at = Some(Instant::now() + delay);
+
+        Err(AdminAuthError::Incorrect)
+    }
+
+    /// True if the admin code is still `DEFAULT_ADMIN_CODE` — a setup
+    /// that was never finished.
+    pub fn is_using_default_code(&self) -> bool {
+        let hash = self.code_hash.read().unwrap().clone();
+        bcrypt::verify(DEFAULT_ADMIN_CODE, &hash).unwrap_or(false)
+    }
+
+    /// Sum of in-flight failed attempts across all tracked sessions,
+    /// i.e. failures that haven't yet been cleared by a successful
+    /// verification or a lockout reset.
+    pub fn recent_failed_attempts(&self) -> u32 {
+        self.attempts.lock().unwrap().values().map(|state| state.failed_attempts).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auth_with_code(code: &str) -> AdminAuth {
+        AdminAuth::new(bcrypt::hash(code, bcrypt::DEFAULT_COST).unwrap())
+    }
+
+    #[test]
+    fn correct_code_verifies_and_resets_attempts() {
+        let auth = auth_with_code("s3cret");
+        assert!(auth.verify_admin_code("session-a", "s3cret").is_ok());
+        assert_eq!(auth.recent_failed_attempts(), 0);
+    }
+
+    #[test]
+    fn incorrect_code_is_rejected_and_counted() {
+        let auth = auth_with_code("s3cret");
+        assert!(matches!(auth.verify_admin_code("session-a", "wrong"), Err(AdminAuthError::Incorrect)));
+        assert_eq!(auth.recent_failed_attempts(), 1);
+    }
+
+    #[test]
+    fn a_second_attempt_within_the_backoff_window_is_locked_out() {
+        let auth = auth_with_code("s3cret");
+        assert!(matches!(auth.verify_admin_code("session-a", "wrong"), Err(AdminAuthError::Incorrect)));
+        assert!(matches!(auth.verify_admin_code("session-a", "s3cret"), Err(AdminAuthError::LockedOut { .. })));
+    }
+
+    #[test]
+    fn failed_attempts_are_tracked_independently_per_session() {
+        let auth = auth_with_code("s3cret");
+        assert!(matches!(auth.verify_admin_code("session-a", "wrong"), Err(AdminAuthError::Incorrect)));
+        assert!(auth.verify_admin_code("session-b", "s3cret").is_ok());
+    }
+
+    #[test]
+    fn is_using_default_code_reflects_the_stored_hash() {
+        let default = auth_with_code(DEFAULT_ADMIN_CODE);
+        assert!(default.is_using_default_code());
+
+        let rotated = auth_with_code("not-the-default");
+        assert!(!rotated.is_using_default_code());
+    }
+
+    #[test]
+    fn update_admin_code_requires_the_current_code() {
+        let auth = auth_with_code("s3cret");
+        assert!(matches!(auth.update_admin_code("wrong", "new-code"), Err(AdminAuthError::RotationDenied)));
+    }
+}
@@ -0,0 +1,136 @@
+//! Simple intrusion detection: a handful of cheap signals that, on
+//! their own, are each plausible but together suggest something is
+//! wrong enough to freeze the system until a human looks.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use chrono::Timelike;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalySignal {
+    FailedAttemptBurst,
+    OutsideAllowedHours,
+    RepeatedHighRiskRequests,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AnomalyThresholds {
+    pub failed_attempt_burst: u32,
+    /// Local hours (0-23, inclusive start, exclusive end) during which
+    /// modification requests are expected. Outside this window is
+    /// treated as anomalous.
+    pub allowed_hours: (u32, u32),
+    pub high_risk_request_burst: usize,
+}
+
+impl Default for AnomalyThresholds {
+    fn default() -> Self {
+        Self { failed_attempt_burst: 8, allowed_hours: (6, 22), high_risk_request_burst: 3 }
+    }
+}
+
+/// Tracks the one piece of state the manager doesn't already have: a
+/// running count of high-risk modification requests. Everything else
+/// (failed attempts, wall-clock hour) is read fresh each check.
+#[derive(Default)]
+pub struct AnomalyDetector {
+    thresholds: AnomalyThresholds,
+    high_risk_requests_seen: AtomicUsize,
+}
+
+impl AnomalyDetector {
+    pub fn new(thresholds: AnomalyThresholds) -> Self {
+        Self { thresholds, high_risk_requests_seen: AtomicUsize::new(0) }
+    }
+
+    pub fn record_modification_request(&self, risk: &str) {
+        if risk == "high" {
+            self.high_risk_requests_seen.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn reset_high_risk_count(&self) {
+        self.high_risk_requests_seen.store(0, Ordering::Relaxed);
+    }
+
+    pub fn check(&self, recent_failed_attempts: u32) -> Vec<AnomalySignal> {
+        let mut signals = Vec::new();
+
+        if recent_failed_attempts >= self.thresholds.failed_attempt_burst {
+            signals.push(AnomalySignal::FailedAttemptBurst);
+        }
+
+        let hour = chrono::Local::now().hour();
+        let (start, end) = self.thresholds.allowed_hours;
+        if hour < start || hour >= end {
+            signals.push(AnomalySignal::OutsideAllowedHours);
+        }
+
+        if self.high_risk_requests_seen.load(Ordering::Relaxed) >= self.thresholds.high_risk_request_burst {
+            signals.push(AnomalySignal::RepeatedHighRiskRequests);
+        }
+
+        signals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector() -> AnomalyDetector {
+        AnomalyDetector::new(AnomalyThresholds { failed_attempt_burst: 5, allowed_hours: (0, 24), high_risk_request_burst: 2 })
+    }
+
+    #[test]
+    fn a_failed_attempt_burst_below_the_threshold_is_not_a_signal() {
+        assert!(!detector().check(4).contains(&AnomalySignal::FailedAttemptBurst));
+    }
+
+    #[test]
+    fn a_failed_attempt_burst_at_the_threshold_is_a_signal() {
+        assert!(detector().check(5).contains(&AnomalySignal::FailedAttemptBurst));
+    }
+
+    #[test]
+    fn high_risk_requests_only_signal_once_the_burst_threshold_is_reached() {
+        let detector = detector();
+        detector.record_modification_request("high");
+        assert!(!detector.check(0).contains(&AnomalySignal::RepeatedHighRiskRequests));
+        detector.record_modification_request("high");
+        assert!(detector.check(0).contains(&AnomalySignal::RepeatedHighRiskRequests));
+    }
+
+    #[test]
+    fn low_risk_requests_never_contribute_to_the_burst_count() {
+        let detector = detector();
+        detector.record_modification_request("low");
+        detector.record_modification_request("low");
+        detector.record_modification_request("low");
+        assert!(!detector.check(0).contains(&AnomalySignal::RepeatedHighRiskRequests));
+    }
+
+    #[test]
+    fn resetting_the_high_risk_count_clears_the_signal() {
+        let detector = detector();
+        detector.record_modification_request("high");
+        detector.record_modification_request("high");
+        detector.reset_high_risk_count();
+        assert!(!detector.check(0).contains(&AnomalySignal::RepeatedHighRiskRequests));
+    }
+
+    #[test]
+    fn an_empty_allowed_window_always_signals_outside_allowed_hours() {
+        // `(0, 0)` makes every hour satisfy `hour >= end`, so this holds
+        // regardless of the wall-clock time the test suite runs at.
+        let detector = AnomalyDetector::new(AnomalyThresholds { failed_attempt_burst: 999, allowed_hours: (0, 0), high_risk_request_burst: 999 });
+        assert!(detector.check(0).contains(&AnomalySignal::OutsideAllowedHours));
+    }
+
+    #[test]
+    fn the_full_day_allowed_window_never_signals_outside_allowed_hours() {
+        assert!(!detector().check(0).contains(&AnomalySignal::OutsideAllowedHours));
+    }
+}
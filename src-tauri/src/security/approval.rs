@@ -0,0 +1,84 @@
+//! Multi-approver quorum with expiry, layered on top of
+//! `ModificationRequest`: a single approval is no longer sufficient by
+//! itself, and stale approvals don't linger forever.
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+/// How long a modification stays open for approval before it expires
+/// and must be resubmitted.
+pub const APPROVAL_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub struct ApprovalTracker {
+    pub opened_at: Instant,
+    pub quorum: usize,
+    approvers: HashSet<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApprovalError {
+    #[error("approval window expired")]
+    Expired,
+    #[error("{approver} already approved this request")]
+    AlreadyApproved { approver: String },
+}
+
+impl ApprovalTracker {
+    pub fn new(quorum: usize) -> Self {
+        Self { opened_at: Instant::now(), quorum, approvers: HashSet::new() }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.opened_at.elapsed() > APPROVAL_WINDOW
+    }
+
+    /// Records an approval from `approver`. Rejects duplicate approvals
+    /// from the same person (quorum must come from distinct approvers)
+    /// and approvals submitted after the window has expired.
+    pub fn approve(&mut self, approver: &str) -> Result<(), ApprovalError> {
+        if self.is_expired() {
+            return Err(ApprovalError::Expired);
+        }
+        if !self.approvers.insert(approver.to_string()) {
+            return Err(ApprovalError::AlreadyApproved { approver: approver.to_string() });
+        }
+        Ok(())
+    }
+
+    pub fn has_quorum(&self) -> bool {
+        !self.is_expired() && self.approvers.len() >= self.quorum
+    }
+}
+
+/// Tracks approval state for modification requests by id, so approvals
+/// coming in across multiple Tauri command calls accumulate correctly.
+#[derive(Default)]
+pub struct ApprovalRegistry {
+    trackers: std::sync::Mutex<std::collections::HashMap<Uuid, ApprovalTracker>>,
+}
+
+impl ApprovalRegistry {
+    pub fn open(&self, request_id: Uuid, quorum: usize) {
+        self.trackers.lock().unwrap().entry(request_id).or_insert_with(|| ApprovalTracker::new(quorum));
+    }
+
+    pub fn approve(&self, request_id: Uuid, approver: &str) -> Result<bool, ApprovalError> {
+        let mut trackers = self.trackers.lock().unwrap();
+        let tracker = trackers.entry(request_id).or_insert_with(|| ApprovalTracker::new(1));
+        tracker.approve(approver)?;
+        Ok(tracker.has_quorum())
+    }
+
+    /// Number of open trackers that haven't expired and haven't yet
+    /// reached quorum.
+    pub fn pending_count(&self) -> usize {
+        self.trackers
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|tracker| !tracker.is_expired() && !tracker.has_quorum())
+            .count()
+    }
+}
@@ -0,0 +1,71 @@
+//! The logging sink `SecurityManager` writes every operation through.
+//! Kept as a trait so the persistent audit log (search, export,
+//! retention) can drop in behind the same interface without
+//! `SecurityManager` changing at all.
+
+use std::sync::Arc;
+
+use crate::audit::entry::{LogEntry, LogLevel};
+use crate::audit::writer::LogWriter;
+use crate::security::events::{EventOutcome, SecurityEvent};
+
+pub trait AuditLogger: Send + Sync {
+    fn log(&self, event: SecurityEvent);
+}
+
+/// The logger `SecurityManager::new` uses when no persistent audit log
+/// is supplied (the app itself passes `LogWriterAuditLogger`): routes
+/// events through `tracing` at a level matching their outcome.
+pub struct TracingAuditLogger;
+
+impl AuditLogger for TracingAuditLogger {
+    fn log(&self, event: SecurityEvent) {
+        match event.outcome {
+            EventOutcome::Success => tracing::info!(?event, "security event"),
+            EventOutcome::Denied => tracing::warn!(?event, "security event"),
+            EventOutcome::Error => tracing::error!(?event, "security event"),
+        }
+    }
+}
+
+/// Routes `SecurityManager` events into the same tamper-evident,
+/// hash-chained log everything else writes to, so admin-auth attempts,
+/// lockdown transitions, rule violations and approval decisions show
+/// up in search/export/alerting instead of only ever being printed via
+/// `tracing`. Denied and successful events are routed to
+/// `LogLevel::Security`; errors (a failed transition, a policy load
+/// failure) to `LogLevel::Critical`, same split the rest of the audit
+/// log uses for retention.
+pub struct LogWriterAuditLogger {
+    writer: Arc<LogWriter>,
+}
+
+impl LogWriterAuditLogger {
+    pub fn new(writer: Arc<LogWriter>) -> Self {
+        Self { writer }
+    }
+}
+
+impl AuditLogger for LogWriterAuditLogger {
+    fn log(&self, event: SecurityEvent) {
+        let level = match event.outcome {
+            EventOutcome::Success | EventOutcome::Denied => LogLevel::Security,
+            EventOutcome::Error => LogLevel::Critical,
+        };
+        let entry = LogEntry {
+            timestamp: event.occurred_at,
+            level,
+            action: event.action.clone(),
+            user: None,
+            message: format!("{:?} from {}", event.outcome, event.source.origin),
+            metadata: serde_json::to_value(&event).unwrap_or(serde_json::Value::Null),
+            session_id: event.session_id.clone(),
+            request_id: Some(event.request_id.to_string()),
+            prev_hash: String::new(),
+            entry_hash: String::new(),
+        };
+        if let Err(err) = self.writer.append(&entry) {
+            tracing::warn!(%err, action = %event.action, "failed to write security event to the audit log");
+        }
+    }
+}
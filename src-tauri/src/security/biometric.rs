@@ -0,0 +1,51 @@
+//! Optional OS-native biometric confirmation (Windows Hello, Touch ID)
+//! for a short list of critical actions. The actual OS call would
+//! happen on the frontend via a Tauri plugin; this module only defines
+//! the contract the backend checks against, the same way `Embedder`
+//! lets the backend stay agnostic about which embedding model is
+//! behind it.
+//!
+//! No such plugin is wired up yet — a Tauri command is frontend-
+//! initiated, so prompting the OS mid-command and blocking on the
+//! result needs a round trip this crate doesn't have a mechanism for.
+//! `SecurityManager` still defaults to `NoopBiometricGate`
+//! (`set_biometric_gate` is never called), so `require_biometric_for`
+//! is accepted and stored but doesn't actually prompt anyone; treat it
+//! as reserved for when that plugin exists, not as an enforced factor
+//! today.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CriticalAction {
+    /// Lifting a `Frozen` lockdown, including recovering from an
+    /// emergency kill — the state machine doesn't distinguish the two,
+    /// they're both a `Frozen -> Normal` transition.
+    LockdownLift,
+    ModificationApproval,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BiometricError {
+    #[error("biometric confirmation was declined or failed")]
+    Declined,
+}
+
+/// Implemented by whatever bridges to the OS prompt (a Tauri plugin
+/// call proxied back into Rust, in the real app). `prompt` blocks until
+/// the user responds.
+pub trait BiometricGate: Send + Sync {
+    fn prompt(&self, reason: &str) -> Result<bool, BiometricError>;
+}
+
+/// Used when no gate is configured: the factor is simply not required.
+/// Kept separate from `None` so call sites don't have to special-case
+/// "disabled" versus "gate not wired up yet".
+pub struct NoopBiometricGate;
+
+impl BiometricGate for NoopBiometricGate {
+    fn prompt(&self, _reason: &str) -> Result<bool, BiometricError> {
+        Ok(true)
+    }
+}
@@ -0,0 +1,60 @@
+//! Per-tool capability grants (reading the filesystem, making network
+//! calls, running shell commands, writing to memory). `CapabilityStore`
+//! and `SecurityManager::check_capability` are the enforcement
+//! primitive a future tool-invocation layer would gate calls through;
+//! this crate doesn't have such a layer yet, so nothing calls
+//! `check_capability` today — grants recorded here aren't enforced
+//! anywhere yet.
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    FilesystemRead,
+    Network,
+    Shell,
+    MemoryWrite,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("tool '{tool_name}' is not granted {capability:?}")]
+pub struct CapabilityDenied {
+    pub tool_name: String,
+    pub capability: Capability,
+}
+
+/// Tracks which capabilities each tool (by name) has been granted.
+/// Absence of a grant means denied, not "ask later" — tools start with
+/// no authority at all.
+#[derive(Debug, Default)]
+pub struct CapabilityStore {
+    grants: HashMap<String, HashSet<Capability>>,
+}
+
+impl CapabilityStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn grant(&mut self, tool_name: &str, capability: Capability) {
+        self.grants.entry(tool_name.to_string()).or_default().insert(capability);
+    }
+
+    pub fn revoke(&mut self, tool_name: &str, capability: Capability) {
+        if let Some(caps) = self.grants.get_mut(tool_name) {
+            caps.remove(&capability);
+        }
+    }
+
+    pub fn is_granted(&self, tool_name: &str, capability: Capability) -> bool {
+        self.grants.get(tool_name).is_some_and(|caps| caps.contains(&capability))
+    }
+
+    pub fn check(&self, tool_name: &str, capability: Capability) -> Result<(), CapabilityDenied> {
+        if self.is_granted(tool_name, capability) {
+            Ok(())
+        } else {
+            Err(CapabilityDenied { tool_name: tool_name.to_string(), capability })
+        }
+    }
+}
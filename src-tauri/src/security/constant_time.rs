@@ -0,0 +1,11 @@
+//! Timing-safe equality for comparing hashes and tokens. A plain `==`
+//! short-circuits on the first differing byte, which leaks how many
+//! leading bytes matched to anyone who can measure response latency
+//! over IPC or HTTP — session tokens and integrity hashes are exactly
+//! that kind of secret.
+
+use subtle::ConstantTimeEq;
+
+pub fn eq(a: &str, b: &str) -> bool {
+    a.as_bytes().ct_eq(b.as_bytes()).into()
+}
@@ -0,0 +1,93 @@
+//! Security events recorded for every SecurityManager operation.
+//! Previously created with `session_id`/`source` left blank; this
+//! module is the single place that knows how to populate them so every
+//! call site doesn't have to thread that context manually.
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::session::AppSession;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityEvent {
+    pub id: Uuid,
+    pub action: String,
+    pub session_id: Option<String>,
+    /// RBAC user id of the caller, when one was supplied — `None` for
+    /// events raised outside a permission-checked command (background
+    /// tasks, admin-code-only actions that predate RBAC).
+    pub user_id: Option<String>,
+    /// Per-call correlation id, distinct from `session_id` — narrows a
+    /// group of events down from "this app run" to "this one command".
+    pub request_id: Uuid,
+    pub source: EventSource,
+    pub outcome: EventOutcome,
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventSource {
+    /// The Tauri window/webview that issued the command, or "system"
+    /// for events raised by a background task.
+    pub origin: String,
+    pub process_id: u32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventOutcome {
+    Success,
+    Denied,
+    Error,
+}
+
+/// Context carried through a request so `SecurityEvent`s built during
+/// it are consistently attributed.
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    pub session_id: Option<String>,
+    /// RBAC user id of the caller, when the command has one to supply
+    /// (see `Permission`-gated commands in `commands::security`).
+    pub user_id: Option<String>,
+    pub request_id: Uuid,
+    pub origin: String,
+}
+
+impl RequestContext {
+    pub fn system() -> Self {
+        Self { session_id: None, user_id: None, request_id: Uuid::new_v4(), origin: "system".to_string() }
+    }
+
+    /// Built per Tauri command: carries the app's session id forward
+    /// and mints a fresh request id for this one call, so everything
+    /// logged while handling it — security events and audit entries
+    /// alike — can be grouped two ways instead of not at all.
+    pub fn from_session(session: &AppSession, origin: &str) -> Self {
+        Self {
+            session_id: Some(session.session_id.to_string()),
+            user_id: None,
+            request_id: Uuid::new_v4(),
+            origin: origin.to_string(),
+        }
+    }
+
+    /// Attaches the RBAC user id that authorized this request, for
+    /// commands gated by `Permission` rather than just the admin code.
+    pub fn with_user(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    pub fn build_event(&self, action: &str, outcome: EventOutcome) -> SecurityEvent {
+        SecurityEvent {
+            id: Uuid::new_v4(),
+            action: action.to_string(),
+            session_id: self.session_id.clone(),
+            user_id: self.user_id.clone(),
+            request_id: self.request_id,
+            source: EventSource { origin: self.origin.clone(), process_id: std::process::id() },
+            outcome,
+            occurred_at: chrono::Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! A stable identifier for "this machine, this OS user", used to bind
+//! admin sessions so a token copied off the device is useless
+//! elsewhere. Exercised in production via `SecurityManager::issue_session`
+//! and `validate_session`, which the `issue_session_command`/
+//! `validate_session_command` Tauri commands call on every session
+//! issue and check.
+
+use sha2::{Digest, Sha256};
+
+/// Combines the hardware id with the OS username and hashes the result,
+/// so the fingerprint is stable across runs on the same machine/account
+/// but doesn't leak the raw hardware id into logs or tokens.
+pub fn current() -> String {
+    let hardware_id = machine_uid::get().unwrap_or_else(|_| "unknown-machine".to_string());
+    let user = whoami::username();
+
+    let mut hasher = Sha256::new();
+    hasher.update(hardware_id.as_bytes());
+    hasher.update(b"|");
+    hasher.update(user.as_bytes());
+    hex::encode(hasher.finalize())
+}
@@ -0,0 +1,56 @@
+//! Wraps modification apply/rollback in a dedicated git branch and
+//! commit, so every self-modification has a reviewable diff and a
+//! revert point in the branch history.
+
+use std::path::Path;
+
+use git2::{Repository, Signature};
+
+use crate::security::modifications::{apply_with_dry_run, ModificationRequest};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GitWorkflowError {
+    #[error(transparent)]
+    Git(#[from] git2::Error),
+    #[error(transparent)]
+    Apply(#[from] crate::security::modifications::ApplyError),
+}
+
+/// Creates a branch named `modification/<id>`, applies the request on
+/// it, and commits the change with the request's rationale as the
+/// commit message. Leaves the repository on the new branch so a
+/// maintainer can review and merge (or delete the branch to discard).
+pub fn apply_on_branch(
+    repo_path: &Path,
+    request: &ModificationRequest,
+) -> Result<(), GitWorkflowError> {
+    let repo = Repository::open(repo_path)?;
+
+    let head_commit = repo.head()?.peel_to_commit()?;
+    let branch_name = format!("modification/{}", request.id);
+    let branch = repo.branch(&branch_name, &head_commit, false)?;
+    repo.set_head(branch.get().name().unwrap_or(&branch_name))?;
+    repo.checkout_head(None)?;
+
+    apply_with_dry_run(request)?;
+    // The original contents and `IntegrityRecord` returned here are
+    // dropped; neither is kept for a later rollback or integrity check.
+
+    let mut index = repo.index()?;
+    index.add_path(request.file_path.strip_prefix(repo_path).unwrap_or(&request.file_path))?;
+    index.write()?;
+    let tree_id = index.write_tree()?;
+    let tree = repo.find_tree(tree_id)?;
+
+    let signature = Signature::now("DYHT Agent", "agent@dyht.local")?;
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        &request.rationale,
+        &tree,
+        &[&head_commit],
+    )?;
+
+    Ok(())
+}
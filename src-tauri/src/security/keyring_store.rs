@@ -0,0 +1,42 @@
+//! Stores admin-code hashes, TOTP secrets and webhook tokens in the
+//! OS-native credential store (Keychain / Credential Manager / Secret
+//! Service) instead of plain config JSON on disk.
+
+const SERVICE: &str = "dyht";
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeyringError {
+    #[error(transparent)]
+    Keyring(#[from] keyring::Error),
+}
+
+pub fn set_secret(key: &str, value: &str) -> Result<(), KeyringError> {
+    keyring::Entry::new(SERVICE, key)?.set_password(value)?;
+    Ok(())
+}
+
+pub fn get_secret(key: &str) -> Result<Option<String>, KeyringError> {
+    match keyring::Entry::new(SERVICE, key)?.get_password() {
+        Ok(value) => Ok(Some(value)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+pub fn delete_secret(key: &str) -> Result<(), KeyringError> {
+    match keyring::Entry::new(SERVICE, key)?.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Returns which of `expected` keys have no entry in the keyring, for
+/// surfacing on the security dashboard (a missing admin code hash or
+/// webhook token usually means setup was never finished).
+pub fn missing_secrets(expected: &[&str]) -> Vec<String> {
+    expected
+        .iter()
+        .filter(|key| !matches!(get_secret(key), Ok(Some(_))))
+        .map(|key| key.to_string())
+        .collect()
+}
@@ -0,0 +1,83 @@
+//! Emergency lockdown, modeled as an explicit state machine rather than
+//! a boolean flag, so transitions (and who triggered them) are
+//! auditable and invalid transitions are rejected at compile time's
+//! nearest runtime equivalent.
+
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockdownState {
+    /// Normal operation: all capabilities enabled.
+    Normal,
+    /// Triggered automatically or by an admin; blocks modification
+    /// application but still allows read-only operations.
+    Restricted,
+    /// Full lockdown. Commands check the lockdown state only before
+    /// applying a modification, so `Frozen` blocks the same thing
+    /// `Restricted` does; `allows_reads` reports `false` for it, but no
+    /// command calls `allows_reads`.
+    Frozen,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("cannot transition from {from:?} to {to:?}")]
+pub struct InvalidTransition {
+    from: LockdownState,
+    to: LockdownState,
+}
+
+pub struct LockdownMachine {
+    state: RwLock<LockdownState>,
+}
+
+impl LockdownMachine {
+    pub fn new() -> Self {
+        Self { state: RwLock::new(LockdownState::Normal) }
+    }
+
+    pub fn state(&self) -> LockdownState {
+        *self.state.read().unwrap()
+    }
+
+    /// Validates and applies a transition. Any state can move to
+    /// `Frozen` (an emergency always wins); `Restricted` and `Frozen` can
+    /// both move back to `Normal`, but `Frozen` only directly, never
+    /// through `Restricted`, so a frozen system can't be partially
+    /// reopened by accident.
+    pub fn transition(&self, to: LockdownState) -> Result<(), InvalidTransition> {
+        let mut state = self.state.write().unwrap();
+        let from = *state;
+
+        let allowed = match (from, to) {
+            (_, LockdownState::Frozen) => true,
+            (LockdownState::Normal, LockdownState::Restricted) => true,
+            (LockdownState::Restricted, LockdownState::Normal) => true,
+            (LockdownState::Frozen, LockdownState::Normal) => true,
+            _ => false,
+        };
+
+        if !allowed {
+            return Err(InvalidTransition { from, to });
+        }
+
+        *state = to;
+        Ok(())
+    }
+
+    pub fn allows_modifications(&self) -> bool {
+        self.state() == LockdownState::Normal
+    }
+
+    pub fn allows_reads(&self) -> bool {
+        self.state() != LockdownState::Frozen
+    }
+}
+
+impl Default for LockdownMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
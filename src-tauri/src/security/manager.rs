@@ -0,0 +1,408 @@
+//! `SecurityManager` is the single entry point the rest of the app goes
+//! through for admin auth, capability checks, lockdown transitions, rule
+//! evaluation and modification approval, so every one of those
+//! operations is logged with outcome in one place instead of relying on
+//! each call site to remember to log separately.
+
+use std::sync::{Arc, RwLock};
+
+use uuid::Uuid;
+
+use crate::security::admin_auth::{AdminAuth, AdminAuthError};
+use crate::security::anomaly::{AnomalyDetector, AnomalySignal};
+use crate::security::approval::{ApprovalError, ApprovalRegistry};
+use crate::security::audit_log::{AuditLogger, TracingAuditLogger};
+use crate::security::biometric::{BiometricError, BiometricGate, CriticalAction, NoopBiometricGate};
+use crate::security::capabilities::{Capability, CapabilityDenied, CapabilityStore};
+use crate::security::events::{EventOutcome, RequestContext};
+use crate::security::lockdown::{InvalidTransition, LockdownMachine, LockdownState};
+use crate::security::modifications::ModificationRequest;
+use crate::security::notifications::{ApprovalEvent, WebhookNotifier};
+use crate::security::policy::{Policy, PolicyError};
+use crate::security::report::SecurityReport;
+use crate::security::rules::{RuleViolation, SecurityRules};
+use crate::security::session::SessionStore;
+use crate::security::settings::{ModificationTypeError, SecuritySettings};
+use crate::security::simulation::{self, SimulationError, SimulationResult};
+use crate::security::static_analysis::{self, AnalysisError, StaticFinding};
+
+#[derive(Debug, thiserror::Error)]
+pub enum LockdownTransitionError {
+    #[error(transparent)]
+    Biometric(#[from] BiometricError),
+    #[error(transparent)]
+    InvalidTransition(#[from] InvalidTransition),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApprovalModificationError {
+    #[error(transparent)]
+    Biometric(#[from] BiometricError),
+    #[error(transparent)]
+    Approval(#[from] ApprovalError),
+}
+
+pub struct SecurityManager {
+    admin_auth: Arc<AdminAuth>,
+    capabilities: RwLock<CapabilityStore>,
+    lockdown: LockdownMachine,
+    approvals: ApprovalRegistry,
+    rules: RwLock<SecurityRules>,
+    settings: RwLock<SecuritySettings>,
+    policy: RwLock<Option<Policy>>,
+    anomaly: AnomalyDetector,
+    sessions: SessionStore,
+    biometric: RwLock<Arc<dyn BiometricGate>>,
+    audit: Arc<dyn AuditLogger>,
+}
+
+impl SecurityManager {
+    pub fn new(admin_auth: Arc<AdminAuth>, rules: SecurityRules) -> Self {
+        Self::with_audit_logger(admin_auth, rules, Arc::new(TracingAuditLogger))
+    }
+
+    pub fn with_audit_logger(
+        admin_auth: Arc<AdminAuth>,
+        rules: SecurityRules,
+        audit: Arc<dyn AuditLogger>,
+    ) -> Self {
+        Self {
+            admin_auth,
+            capabilities: RwLock::new(CapabilityStore::new()),
+            lockdown: LockdownMachine::new(),
+            approvals: ApprovalRegistry::default(),
+            rules: RwLock::new(rules),
+            settings: RwLock::new(SecuritySettings::default()),
+            policy: RwLock::new(None),
+            anomaly: AnomalyDetector::default(),
+            sessions: SessionStore::new(),
+            biometric: RwLock::new(Arc::new(NoopBiometricGate)),
+            audit,
+        }
+    }
+
+    /// Plugs in the real biometric gate (backed by the frontend's
+    /// Tauri plugin call). Until this is called, biometric checks
+    /// always pass, regardless of `require_biometric_for`.
+    pub fn set_biometric_gate(&self, gate: Arc<dyn BiometricGate>) {
+        *self.biometric.write().unwrap() = gate;
+    }
+
+    fn require_biometric(
+        &self,
+        action: CriticalAction,
+        reason: &str,
+        ctx: &RequestContext,
+    ) -> Result<(), BiometricError> {
+        if !self.settings.read().unwrap().require_biometric_for.contains(&action) {
+            return Ok(());
+        }
+        let gate = self.biometric.read().unwrap().clone();
+        let result = match gate.prompt(reason) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BiometricError::Declined),
+            Err(err) => Err(err),
+        };
+        let outcome = if result.is_ok() { EventOutcome::Success } else { EventOutcome::Denied };
+        self.audit.log(ctx.build_event(&format!("biometric_check:{action:?}"), outcome));
+        result
+    }
+
+    /// Issues a session token bound to the current machine fingerprint,
+    /// recording the binding in the audit trail.
+    pub fn issue_session(&self, ctx: &RequestContext) -> String {
+        let fingerprint = crate::security::fingerprint::current();
+        let token = self.sessions.issue(&fingerprint);
+        self.audit.log(ctx.build_event(&format!("issue_session:{fingerprint}"), EventOutcome::Success));
+        token
+    }
+
+    /// Validates `token` against the current machine's fingerprint,
+    /// logging a denial (with the fingerprint that was checked) if it
+    /// was issued elsewhere or has expired.
+    pub fn validate_session(&self, token: &str, ctx: &RequestContext) -> bool {
+        let fingerprint = crate::security::fingerprint::current();
+        let valid = self.sessions.is_valid(token, &fingerprint);
+        let outcome = if valid { EventOutcome::Success } else { EventOutcome::Denied };
+        self.audit.log(ctx.build_event(&format!("validate_session:{fingerprint}"), outcome));
+        valid
+    }
+
+    pub fn record_modification_request(&self, risk: &str) {
+        self.anomaly.record_modification_request(risk);
+    }
+
+    /// Checks the anomaly signals and, if any tripped, freezes the
+    /// system and sends a high-priority webhook notification. Returns
+    /// the signals that fired (empty if none did). A lockdown that's
+    /// already `Frozen` stays `Frozen`; this never downgrades it.
+    pub async fn enforce_anomaly_lockdown(
+        &self,
+        notifier: &WebhookNotifier,
+        ctx: &RequestContext,
+    ) -> Vec<AnomalySignal> {
+        let signals = self.anomaly.check(self.admin_auth.recent_failed_attempts());
+        if signals.is_empty() {
+            return signals;
+        }
+
+        let _ = self.lockdown.transition(LockdownState::Frozen);
+        self.audit.log(ctx.build_event("anomaly_lockdown", EventOutcome::Denied));
+        notifier
+            .notify(&ApprovalEvent::AnomalyDetected {
+                signals: signals.iter().map(|s| format!("{s:?}")).collect(),
+            })
+            .await;
+        signals
+    }
+
+    pub fn set_security_settings(&self, settings: SecuritySettings, ctx: &RequestContext) {
+        *self.settings.write().unwrap() = settings;
+        self.audit.log(ctx.build_event("security_settings_change", EventOutcome::Success));
+    }
+
+    /// Loads `path` as a `Policy`, validates it, and applies its rules
+    /// and settings. Called at startup and again from the
+    /// `reload_policy` command when an operator edits the file on
+    /// disk — a validation failure leaves the previously loaded policy
+    /// in place rather than clearing it.
+    pub fn load_policy(&self, path: &std::path::Path, ctx: &RequestContext) -> Result<(), PolicyError> {
+        let policy = Policy::load(path);
+        let outcome = if policy.is_ok() { EventOutcome::Success } else { EventOutcome::Error };
+        self.audit.log(ctx.build_event("load_policy", outcome));
+
+        let policy = policy?;
+        *self.rules.write().unwrap() = policy.rules.clone();
+        *self.settings.write().unwrap() = policy.settings.clone();
+        *self.policy.write().unwrap() = Some(policy);
+        Ok(())
+    }
+
+    pub fn current_policy_risk(&self, changed_lines: usize) -> Option<&'static str> {
+        self.policy.read().unwrap().as_ref().map(|p| p.risk_for_changed_lines(changed_lines))
+    }
+
+    pub fn grant_capability(&self, tool_name: &str, capability: Capability) {
+        self.capabilities.write().unwrap().grant(tool_name, capability);
+    }
+
+    pub fn revoke_capability(&self, tool_name: &str, capability: Capability) {
+        self.capabilities.write().unwrap().revoke(tool_name, capability);
+    }
+
+    /// Checks whether `tool_name` has been granted `capability`, logging
+    /// the outcome either way. Not called from anywhere in this crate
+    /// yet — there's no tool-invocation call site to gate — but exists
+    /// as the primitive a future one would use, so the check doesn't need to be designed from scratch
+    /// alongside whatever introduces that call site.
+    pub fn check_capability(
+        &self,
+        tool_name: &str,
+        capability: Capability,
+        ctx: &RequestContext,
+    ) -> Result<(), CapabilityDenied> {
+        let result = self.capabilities.read().unwrap().check(tool_name, capability);
+        let outcome = if result.is_ok() { EventOutcome::Success } else { EventOutcome::Denied };
+        self.audit.log(ctx.build_event(&format!("capability_check:{tool_name}"), outcome));
+        result
+    }
+
+    /// Verifies the admin code for `session_id`, logging the outcome
+    /// either way so failed verifications show up in the audit trail
+    /// even if the caller never checks the error.
+    pub fn verify_admin_code(
+        &self,
+        session_id: &str,
+        code: &str,
+        ctx: &RequestContext,
+    ) -> Result<(), AdminAuthError> {
+        let result = self.admin_auth.verify_admin_code(session_id, code);
+        let outcome = if result.is_ok() { EventOutcome::Success } else { EventOutcome::Denied };
+        self.audit.log(ctx.build_event("verify_admin_code", outcome));
+        result
+    }
+
+    /// Transitions the lockdown state. Lifting a `Frozen` lockdown back
+    /// to `Normal` additionally requires biometric confirmation if
+    /// `SecuritySettings::require_biometric_for` lists
+    /// `CriticalAction::LockdownLift`.
+    pub fn transition_lockdown(
+        &self,
+        to: LockdownState,
+        ctx: &RequestContext,
+    ) -> Result<(), LockdownTransitionError> {
+        if self.lockdown.state() == LockdownState::Frozen && to == LockdownState::Normal {
+            self.require_biometric(CriticalAction::LockdownLift, "Lift emergency lockdown", ctx)?;
+        }
+
+        let result = self.lockdown.transition(to);
+        let outcome = if result.is_ok() { EventOutcome::Success } else { EventOutcome::Error };
+        self.audit.log(ctx.build_event(&format!("lockdown_transition:{to:?}"), outcome));
+        Ok(result?)
+    }
+
+    pub fn lockdown_state(&self) -> LockdownState {
+        self.lockdown.state()
+    }
+
+    pub fn set_rules(&self, rules: SecurityRules, ctx: &RequestContext) {
+        *self.rules.write().unwrap() = rules;
+        self.audit.log(ctx.build_event("rule_change", EventOutcome::Success));
+    }
+
+    /// Evaluates `request` against the current rules, logging a denial
+    /// event if anything violates them.
+    pub fn evaluate_rules(
+        &self,
+        request: &ModificationRequest,
+        ctx: &RequestContext,
+    ) -> Vec<RuleViolation> {
+        let mut violations = crate::security::rules::evaluate(&self.rules.read().unwrap(), request);
+        if let Some(policy) = self.policy.read().unwrap().as_ref() {
+            for pattern in &policy.auto_reject_patterns {
+                if regex::Regex::new(pattern).is_ok_and(|re| re.is_match(&request.diff)) {
+                    violations.push(RuleViolation {
+                        rule: "auto_reject_patterns".to_string(),
+                        detail: format!("diff matches auto-reject pattern `{pattern}`"),
+                    });
+                }
+            }
+        }
+        let outcome = if violations.is_empty() { EventOutcome::Success } else { EventOutcome::Denied };
+        self.audit.log(ctx.build_event("evaluate_rules", outcome));
+        violations
+    }
+
+    /// Rejects the request outright if code modification is globally
+    /// disabled or if the file's modification type isn't on the
+    /// allowed list, before it ever reaches rule evaluation or
+    /// approval.
+    pub fn check_modification_type(
+        &self,
+        request: &ModificationRequest,
+        ctx: &RequestContext,
+    ) -> Result<(), ModificationTypeError> {
+        let result = self.settings.read().unwrap().check(&request.file_path);
+        let outcome = if result.is_ok() { EventOutcome::Success } else { EventOutcome::Denied };
+        self.audit.log(ctx.build_event("check_modification_type", outcome));
+        result.map(|_| ())
+    }
+
+    /// Records an approval, logging whichever outcome results (a failed
+    /// approval — expired window or duplicate approver — is logged as a
+    /// rejection of that attempt, not a rejection of the modification
+    /// itself).
+    /// Records an approval. Additionally requires biometric
+    /// confirmation first if `SecuritySettings::require_biometric_for`
+    /// lists `CriticalAction::ModificationApproval`.
+    pub fn approve_modification(
+        &self,
+        request_id: Uuid,
+        approver: &str,
+        ctx: &RequestContext,
+    ) -> Result<bool, ApprovalModificationError> {
+        self.require_biometric(
+            CriticalAction::ModificationApproval,
+            &format!("Approve modification {request_id}"),
+            ctx,
+        )?;
+
+        let result = self.approvals.approve(request_id, approver);
+        let outcome = if result.is_ok() { EventOutcome::Success } else { EventOutcome::Denied };
+        self.audit.log(ctx.build_event(&format!("approve_modification:{request_id}"), outcome));
+        Ok(result?)
+    }
+
+    pub fn open_approval(&self, request_id: Uuid, quorum: usize, ctx: &RequestContext) {
+        self.approvals.open(request_id, quorum);
+        self.audit.log(ctx.build_event(&format!("open_approval:{request_id}"), EventOutcome::Success));
+    }
+
+    /// Builds a `WebhookNotifier` from the configured URLs and the
+    /// signing secret in the keyring, or `None` if no URLs are
+    /// configured — callers should treat that as "notifications
+    /// disabled", not an error.
+    pub fn webhook_notifier(&self) -> Option<WebhookNotifier> {
+        let urls = self.settings.read().unwrap().webhook_urls.clone();
+        if urls.is_empty() {
+            return None;
+        }
+        let secret = crate::security::keyring_store::get_secret("webhook_signing_secret").ok().flatten().unwrap_or_default();
+        Some(WebhookNotifier::new(urls, secret))
+    }
+
+    pub fn reject_modification(&self, request_id: Uuid, ctx: &RequestContext) {
+        self.audit.log(ctx.build_event(&format!("reject_modification:{request_id}"), EventOutcome::Denied));
+    }
+
+    /// Runs the `syn`-based static analyzer against `request` if its
+    /// target is a `.rs` file, storing the findings on the request and
+    /// logging a denial event if anything was flagged (the caller
+    /// decides whether a finding actually blocks approval — this just
+    /// makes sure it's never silently skipped).
+    pub fn analyze_modification(
+        &self,
+        request: &mut ModificationRequest,
+        ctx: &RequestContext,
+    ) -> Result<&[StaticFinding], AnalysisError> {
+        if request.file_path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            request.findings = Vec::new();
+            return Ok(&request.findings);
+        }
+
+        let original = std::fs::read_to_string(&request.file_path).unwrap_or_default();
+        let findings = static_analysis::analyze_diff(&original, &request.diff)?;
+        let outcome = if findings.is_empty() { EventOutcome::Success } else { EventOutcome::Denied };
+        self.audit.log(ctx.build_event(&format!("static_analysis:{}", request.id), outcome));
+        request.findings = findings;
+        Ok(&request.findings)
+    }
+
+    /// Runs `simulation::simulate` for `request` against `repo_path`
+    /// and the current rules. The result is returned to the caller to
+    /// attach (via `ModificationRegistry::set_simulation`) — this
+    /// method doesn't touch the registry itself, since `SecurityManager`
+    /// doesn't own it.
+    pub fn simulate_modification(
+        &self,
+        repo_path: &std::path::Path,
+        request: &ModificationRequest,
+        ctx: &RequestContext,
+    ) -> Result<SimulationResult, SimulationError> {
+        let result = simulation::simulate(repo_path, request, &self.rules.read().unwrap());
+        let outcome = match &result {
+            Ok(r) if r.build_passed && r.test_passed && r.rule_violations.is_empty() => EventOutcome::Success,
+            Ok(_) => EventOutcome::Denied,
+            Err(_) => EventOutcome::Error,
+        };
+        self.audit.log(ctx.build_event(&format!("simulate_modification:{}", request.id), outcome));
+        result
+    }
+
+    /// Builds a point-in-time snapshot of security posture for a
+    /// dashboard. `expected_secrets` is the set of keyring keys setup is
+    /// supposed to have populated (e.g. the admin code hash, TOTP
+    /// secret) — anything missing from that list is flagged.
+    pub fn security_report(&self, expected_secrets: &[&str]) -> SecurityReport {
+        let mut warnings = Vec::new();
+        if self.admin_auth.is_using_default_code() {
+            warnings.push("admin code has not been changed from the default".to_string());
+        }
+
+        SecurityReport {
+            lockdown_state: self.lockdown.state(),
+            recent_failed_auth_attempts: self.admin_auth.recent_failed_attempts(),
+            pending_approvals: self.approvals.pending_count(),
+            disabled_rule_checks: self
+                .rules
+                .read()
+                .unwrap()
+                .disabled_checks()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            secrets_missing_from_keyring: crate::security::keyring_store::missing_secrets(expected_secrets),
+            warnings,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Admin authentication, authorization and the modification-approval
+//! workflow that guards changes the agent proposes to its own code or
+//! configuration.
+
+pub mod admin_auth;
+pub mod approval;
+pub mod audit_log;
+pub mod biometric;
+pub mod capabilities;
+pub mod constant_time;
+pub mod events;
+pub mod fingerprint;
+pub mod git_workflow;
+pub mod keyring_store;
+pub mod lockdown;
+pub mod manager;
+pub mod modifications;
+pub mod notifications;
+pub mod policy;
+pub mod rbac;
+pub mod report;
+pub mod rules;
+pub mod session;
+pub mod settings;
+pub mod simulation;
+pub mod static_analysis;
+pub mod totp;
@@ -0,0 +1,223 @@
+//! Self-modification workflow: the agent proposes a change to its own
+//! code or config, a human approves it, and it's applied through a
+//! sandboxed dry-run with rollback rather than written directly.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModificationRequest {
+    pub id: Uuid,
+    pub file_path: PathBuf,
+    pub diff: String,
+    pub rationale: String,
+    pub status: ModificationStatus,
+    /// Populated by `static_analysis::analyze_diff` for `.rs` targets
+    /// before the request is eligible for approval.
+    #[serde(default)]
+    pub findings: Vec<crate::security::static_analysis::StaticFinding>,
+    /// Threaded discussion: approvers requesting changes or explaining
+    /// a rejection without leaving the app.
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+    /// Result of the most recent `simulate_modification` run, if any.
+    #[serde(default)]
+    pub simulation: Option<crate::security::simulation::SimulationResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: Uuid,
+    pub author: String,
+    pub text: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("modification request {0} not found")]
+pub struct ModificationNotFound(pub Uuid);
+
+/// In-memory registry of modification requests, keyed by id, so
+/// comments added across separate Tauri command calls accumulate on
+/// the same request instead of being lost between invocations.
+#[derive(Default)]
+pub struct ModificationRegistry {
+    requests: Mutex<HashMap<Uuid, ModificationRequest>>,
+}
+
+impl ModificationRegistry {
+    pub fn insert(&self, request: ModificationRequest) {
+        self.requests.lock().unwrap().insert(request.id, request);
+    }
+
+    pub fn get(&self, request_id: Uuid) -> Result<ModificationRequest, ModificationNotFound> {
+        self.requests
+            .lock()
+            .unwrap()
+            .get(&request_id)
+            .cloned()
+            .ok_or(ModificationNotFound(request_id))
+    }
+
+    pub fn add_comment(
+        &self,
+        request_id: Uuid,
+        author: &str,
+        text: &str,
+    ) -> Result<Comment, ModificationNotFound> {
+        let mut requests = self.requests.lock().unwrap();
+        let request = requests.get_mut(&request_id).ok_or(ModificationNotFound(request_id))?;
+        let comment = Comment {
+            id: Uuid::new_v4(),
+            author: author.to_string(),
+            text: text.to_string(),
+            created_at: chrono::Utc::now(),
+        };
+        request.comments.push(comment.clone());
+        Ok(comment)
+    }
+
+    pub fn list_comments(&self, request_id: Uuid) -> Result<Vec<Comment>, ModificationNotFound> {
+        Ok(self.get(request_id)?.comments)
+    }
+
+    /// Attaches a simulation result to the request without touching its
+    /// status — simulating never approves or rejects anything.
+    pub fn set_simulation(
+        &self,
+        request_id: Uuid,
+        result: crate::security::simulation::SimulationResult,
+    ) -> Result<(), ModificationNotFound> {
+        let mut requests = self.requests.lock().unwrap();
+        let request = requests.get_mut(&request_id).ok_or(ModificationNotFound(request_id))?;
+        request.simulation = Some(result);
+        Ok(())
+    }
+
+    /// Attaches static-analysis findings without touching status —
+    /// analyzing never approves or rejects anything either, it just
+    /// gives an approver something to look at.
+    pub fn set_findings(
+        &self,
+        request_id: Uuid,
+        findings: Vec<crate::security::static_analysis::StaticFinding>,
+    ) -> Result<(), ModificationNotFound> {
+        let mut requests = self.requests.lock().unwrap();
+        let request = requests.get_mut(&request_id).ok_or(ModificationNotFound(request_id))?;
+        request.findings = findings;
+        Ok(())
+    }
+
+    pub fn set_status(
+        &self,
+        request_id: Uuid,
+        status: ModificationStatus,
+    ) -> Result<(), ModificationNotFound> {
+        let mut requests = self.requests.lock().unwrap();
+        let request = requests.get_mut(&request_id).ok_or(ModificationNotFound(request_id))?;
+        request.status = status;
+        Ok(())
+    }
+}
+
+/// Integrity record for an applied modification: hashes of the file
+/// before and after, so a later audit can verify the file on disk still
+/// matches what was actually approved and applied (not tampered with
+/// afterward, and not silently re-applied with a different diff).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityRecord {
+    pub modification_id: Uuid,
+    pub before_hash: String,
+    pub after_hash: String,
+}
+
+fn sha256_hex(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModificationStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Applied,
+    RolledBack,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApplyError {
+    #[error("modification is not approved")]
+    NotApproved,
+    #[error("dry run failed: {0}")]
+    DryRunFailed(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Applies `request` to the real working tree only after first applying
+/// it to a scratch copy of the target file and confirming the patch
+/// applies cleanly there. Returns the original file contents (which the
+/// caller must keep to support `rollback`) and an `IntegrityRecord`
+/// hashing the before/after state.
+pub fn apply_with_dry_run(
+    request: &ModificationRequest,
+) -> Result<(String, IntegrityRecord), ApplyError> {
+    if request.status != ModificationStatus::Approved {
+        return Err(ApplyError::NotApproved);
+    }
+
+    let original = std::fs::read_to_string(&request.file_path)?;
+
+    let dry_run_dir = std::env::temp_dir().join(format!("dyht-modification-{}", request.id));
+    std::fs::create_dir_all(&dry_run_dir)?;
+    let scratch_path = dry_run_dir.join(
+        request.file_path.file_name().unwrap_or_default(),
+    );
+    std::fs::write(&scratch_path, &original)?;
+
+    apply_patch(&scratch_path, &request.diff)
+        .map_err(|e| ApplyError::DryRunFailed(e.to_string()))?;
+
+    apply_patch(&request.file_path, &request.diff)
+        .map_err(|e| ApplyError::DryRunFailed(e.to_string()))?;
+
+    let applied = std::fs::read_to_string(&request.file_path)?;
+    let record = IntegrityRecord {
+        modification_id: request.id,
+        before_hash: sha256_hex(&original),
+        after_hash: sha256_hex(&applied),
+    };
+
+    let _ = std::fs::remove_dir_all(&dry_run_dir);
+    Ok((original, record))
+}
+
+/// Verifies that `file_path`'s current contents still hash to
+/// `record.after_hash`, catching tampering or drift since the
+/// modification was applied.
+pub fn verify_integrity(file_path: &PathBuf, record: &IntegrityRecord) -> Result<bool, ApplyError> {
+    let current = std::fs::read_to_string(file_path)?;
+    Ok(crate::security::constant_time::eq(&sha256_hex(&current), &record.after_hash))
+}
+
+/// Restores `file_path` to `original_contents`, undoing a prior apply.
+pub fn rollback(file_path: &PathBuf, original_contents: &str) -> Result<(), ApplyError> {
+    std::fs::write(file_path, original_contents)?;
+    Ok(())
+}
+
+fn apply_patch(path: &PathBuf, diff: &str) -> anyhow::Result<()> {
+    let patch = diffy::Patch::from_str(diff)?;
+    let original = std::fs::read_to_string(path)?;
+    let patched = diffy::apply(&original, &patch)?;
+    std::fs::write(path, patched)?;
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Notifies configured webhooks when a modification needs approval or
+//! reaches quorum, and when the anomaly detector freezes the system, so
+//! approvers don't have to poll the UI. `ApprovalEvent::Expired` is
+//! defined, but nothing sends it.
+
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use tracing::warn;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ApprovalEvent {
+    PendingApproval { request_id: Uuid, rationale: String },
+    QuorumReached { request_id: Uuid },
+    Expired { request_id: Uuid },
+    /// High-priority: the anomaly detector tripped and the system was
+    /// frozen automatically. Not tied to a single modification request.
+    AnomalyDetected { signals: Vec<String> },
+}
+
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    webhook_urls: Vec<String>,
+    signing_secret: String,
+}
+
+impl WebhookNotifier {
+    pub fn new(webhook_urls: Vec<String>, signing_secret: String) -> Self {
+        Self { client: reqwest::Client::new(), webhook_urls, signing_secret }
+    }
+
+    /// Posts `event` as JSON to every configured webhook, signed with
+    /// an `X-DYHT-Signature` HMAC-SHA256 header over the raw body so a
+    /// receiver can authenticate the sender. A failing webhook is
+    /// logged and does not stop delivery to the others.
+    pub async fn notify(&self, event: &ApprovalEvent) {
+        let body = match serde_json::to_vec(event) {
+            Ok(body) => body,
+            Err(err) => {
+                warn!(%err, "failed to serialize approval event");
+                return;
+            }
+        };
+        let signature = sign(&self.signing_secret, &body);
+
+        for url in &self.webhook_urls {
+            let result = self
+                .client
+                .post(url)
+                .header("X-DYHT-Signature", &signature)
+                .header("Content-Type", "application/json")
+                .body(body.clone())
+                .send()
+                .await;
+            if let Err(err) = result {
+                warn!(%url, %err, "failed to deliver approval webhook");
+            }
+        }
+    }
+}
+
+fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Verifies a received `X-DYHT-Signature` header against `body` and
+/// `secret`. `Mac::verify_slice` compares in constant time internally,
+/// so this is safe to call directly on attacker-supplied signatures.
+pub fn verify_signature(secret: &str, body: &[u8], signature_hex: &str) -> bool {
+    let Ok(signature) = hex::decode(signature_hex) else {
+        return false;
+    };
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&signature).is_ok()
+}
@@ -0,0 +1,93 @@
+//! Security policy as a single YAML file under the config directory,
+//! rather than scattered across `SecurityRules`/`SecuritySettings`
+//! construction calls in code — so changing what's allowed doesn't
+//! require a rebuild, just a reload.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::security::rules::SecurityRules;
+use crate::security::settings::SecuritySettings;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RiskThresholds {
+    /// Changed-line counts at or under this are "low" risk.
+    pub low_max_changed_lines: usize,
+    /// Changed-line counts at or under this (but over `low`) are
+    /// "medium" risk; anything higher is "high".
+    pub medium_max_changed_lines: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequiredApprovers {
+    pub low: usize,
+    pub medium: usize,
+    pub high: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Policy {
+    pub rules: SecurityRules,
+    #[serde(default)]
+    pub settings: SecuritySettings,
+    pub risk_thresholds: RiskThresholds,
+    /// Diffs matching any of these patterns are rejected at rule
+    /// evaluation (`SecurityManager::evaluate_rules`), regardless of
+    /// risk level or approvals.
+    #[serde(default)]
+    pub auto_reject_patterns: Vec<String>,
+    pub required_approvers: RequiredApprovers,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PolicyError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("failed to parse policy YAML: {0}")]
+    Parse(#[from] serde_yaml::Error),
+    #[error("invalid policy: {0}")]
+    Invalid(String),
+}
+
+impl Policy {
+    pub fn load(path: &Path) -> Result<Self, PolicyError> {
+        let raw = std::fs::read_to_string(path)?;
+        let policy: Policy = serde_yaml::from_str(&raw)?;
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    fn validate(&self) -> Result<(), PolicyError> {
+        if self.required_approvers.high == 0 {
+            return Err(PolicyError::Invalid(
+                "required_approvers.high must be at least 1".to_string(),
+            ));
+        }
+        if self.risk_thresholds.low_max_changed_lines > self.risk_thresholds.medium_max_changed_lines {
+            return Err(PolicyError::Invalid(
+                "risk_thresholds.low_max_changed_lines must not exceed medium_max_changed_lines"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn risk_for_changed_lines(&self, changed_lines: usize) -> &'static str {
+        if changed_lines <= self.risk_thresholds.low_max_changed_lines {
+            "low"
+        } else if changed_lines <= self.risk_thresholds.medium_max_changed_lines {
+            "medium"
+        } else {
+            "high"
+        }
+    }
+
+    pub fn required_approvers_for(&self, risk: &str) -> usize {
+        match risk {
+            "low" => self.required_approvers.low,
+            "medium" => self.required_approvers.medium,
+            _ => self.required_approvers.high,
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Role-based access control, layered on top of (not replacing) the
+//! single-admin-code model: the admin code still gates every command
+//! that takes it as the root credential, and `UserStore`/`Permission` narrow
+//! what an already-authenticated caller is additionally allowed to do.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Owner,
+    Admin,
+    Reviewer,
+    ReadOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    ViewAuditLog,
+    ApproveModification,
+    ApplyModification,
+    ManageUsers,
+    ManageSecuritySettings,
+}
+
+impl Role {
+    /// Permissions granted by this role, additive with any other roles
+    /// the same user holds.
+    pub fn permissions(self) -> &'static [Permission] {
+        use Permission::*;
+        match self {
+            Role::Owner => &[ViewAuditLog, ApproveModification, ApplyModification, ManageUsers, ManageSecuritySettings],
+            Role::Admin => &[ViewAuditLog, ApproveModification, ApplyModification, ManageSecuritySettings],
+            Role::Reviewer => &[ViewAuditLog, ApproveModification],
+            Role::ReadOnly => &[ViewAuditLog],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    pub id: String,
+    pub display_name: String,
+    pub roles: Vec<Role>,
+}
+
+impl User {
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.roles.iter().any(|role| role.permissions().contains(&permission))
+    }
+
+    pub fn permissions(&self) -> HashSet<Permission> {
+        self.roles.iter().flat_map(|role| role.permissions().iter().copied()).collect()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("user '{0}' not found")]
+pub struct UserNotFound(pub String);
+
+/// In-memory registry of RBAC users, keyed by id, mirroring
+/// `ModificationRegistry`'s `Mutex<HashMap<..>>` pattern. Like
+/// modification requests, users are not persisted: both registries
+/// start empty on every launch.
+#[derive(Default)]
+pub struct UserStore {
+    users: Mutex<HashMap<String, User>>,
+}
+
+impl UserStore {
+    pub fn create(&self, id: &str, display_name: &str) -> User {
+        let user = User { id: id.to_string(), display_name: display_name.to_string(), roles: Vec::new() };
+        self.users.lock().unwrap().insert(id.to_string(), user.clone());
+        user
+    }
+
+    pub fn get(&self, id: &str) -> Result<User, UserNotFound> {
+        self.users.lock().unwrap().get(id).cloned().ok_or_else(|| UserNotFound(id.to_string()))
+    }
+
+    pub fn list(&self) -> Vec<User> {
+        self.users.lock().unwrap().values().cloned().collect()
+    }
+
+    pub fn assign_role(&self, id: &str, role: Role) -> Result<User, UserNotFound> {
+        let mut users = self.users.lock().unwrap();
+        let user = users.get_mut(id).ok_or_else(|| UserNotFound(id.to_string()))?;
+        if !user.roles.contains(&role) {
+            user.roles.push(role);
+        }
+        Ok(user.clone())
+    }
+
+    /// Whether `id` currently holds `permission`. A user that doesn't
+    /// exist has none, same as a user with no roles — this is the
+    /// single check point command handlers use instead of loading the
+    /// user and calling `has_permission` themselves.
+    pub fn has_permission(&self, id: &str, permission: Permission) -> bool {
+        self.users.lock().unwrap().get(id).is_some_and(|user| user.has_permission(permission))
+    }
+
+    /// Whether any user already holds `ManageUsers` — used to allow the
+    /// very first user to be created/promoted without already holding
+    /// a permission nobody can hold yet.
+    pub fn has_any_manager(&self) -> bool {
+        self.users.lock().unwrap().values().any(|user| user.has_permission(Permission::ManageUsers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(roles: &[Role]) -> User {
+        User { id: "u1".to_string(), display_name: "Test User".to_string(), roles: roles.to_vec() }
+    }
+
+    #[test]
+    fn owner_has_every_permission() {
+        let owner = user(&[Role::Owner]);
+        for permission in [
+            Permission::ViewAuditLog,
+            Permission::ApproveModification,
+            Permission::ApplyModification,
+            Permission::ManageUsers,
+            Permission::ManageSecuritySettings,
+        ] {
+            assert!(owner.has_permission(permission));
+        }
+    }
+
+    #[test]
+    fn read_only_can_view_but_not_approve() {
+        let viewer = user(&[Role::ReadOnly]);
+        assert!(viewer.has_permission(Permission::ViewAuditLog));
+        assert!(!viewer.has_permission(Permission::ApproveModification));
+    }
+
+    #[test]
+    fn permissions_are_additive_across_roles() {
+        let combined = user(&[Role::ReadOnly, Role::Reviewer]);
+        assert!(combined.has_permission(Permission::ViewAuditLog));
+        assert!(combined.has_permission(Permission::ApproveModification));
+        assert!(!combined.has_permission(Permission::ApplyModification));
+    }
+
+    #[test]
+    fn a_user_with_no_roles_has_no_permissions() {
+        let nobody = user(&[]);
+        assert!(nobody.permissions().is_empty());
+    }
+
+    #[test]
+    fn a_newly_created_user_has_no_roles_or_permissions() {
+        let store = UserStore::default();
+        let created = store.create("u1", "Test User");
+        assert!(created.roles.is_empty());
+        assert!(!store.has_permission("u1", Permission::ViewAuditLog));
+    }
+
+    #[test]
+    fn assigning_a_role_grants_its_permissions() {
+        let store = UserStore::default();
+        store.create("u1", "Test User");
+        store.assign_role("u1", Role::Reviewer).unwrap();
+        assert!(store.has_permission("u1", Permission::ApproveModification));
+        assert!(!store.has_permission("u1", Permission::ApplyModification));
+    }
+
+    #[test]
+    fn assigning_a_role_twice_does_not_duplicate_it() {
+        let store = UserStore::default();
+        store.create("u1", "Test User");
+        store.assign_role("u1", Role::ReadOnly).unwrap();
+        let user = store.assign_role("u1", Role::ReadOnly).unwrap();
+        assert_eq!(user.roles.iter().filter(|r| *r == Role::ReadOnly).count(), 1);
+    }
+
+    #[test]
+    fn assigning_a_role_to_an_unknown_user_fails() {
+        let store = UserStore::default();
+        assert!(store.assign_role("ghost", Role::Owner).is_err());
+    }
+
+    #[test]
+    fn an_unconfigured_store_has_no_manager() {
+        let store = UserStore::default();
+        assert!(!store.has_any_manager());
+        store.create("u1", "Owner");
+        store.assign_role("u1", Role::Owner).unwrap();
+        assert!(store.has_any_manager());
+    }
+}
@@ -0,0 +1,17 @@
+//! Summary snapshot of the security subsystem's state, for a dashboard
+//! rather than an audit trail — a human glancing at this should be able
+//! to tell "is anything obviously wrong right now?".
+
+use serde::Serialize;
+
+use crate::security::lockdown::LockdownState;
+
+#[derive(Debug, Serialize)]
+pub struct SecurityReport {
+    pub lockdown_state: LockdownState,
+    pub recent_failed_auth_attempts: u32,
+    pub pending_approvals: usize,
+    pub disabled_rule_checks: Vec<String>,
+    pub secrets_missing_from_keyring: Vec<String>,
+    pub warnings: Vec<String>,
+}
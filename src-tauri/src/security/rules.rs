@@ -0,0 +1,107 @@
+//! Security rules evaluated against a proposed modification before it's
+//! eligible for approval. Previously `SecurityRules` existed only as
+//! config data with nothing actually checking a diff against it.
+
+use once_cell::sync::OnceCell;
+use regex::RegexSet;
+use serde::{Deserialize, Serialize};
+
+use crate::security::modifications::ModificationRequest;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SecurityRules {
+    /// Paths a modification may never touch, e.g. the security module
+    /// itself or CI config.
+    pub forbidden_paths: Vec<String>,
+    /// Regex patterns that, if any match a diff's added lines, reject
+    /// the modification outright (credentials, disabling checks, etc).
+    /// Compiled once into `compiled_patterns` rather than re-compiled on
+    /// every evaluation, since rule sets rarely change at runtime.
+    pub forbidden_patterns: Vec<String>,
+    /// Largest diff, in changed lines, allowed without manual escalation.
+    pub max_changed_lines: usize,
+    #[serde(skip)]
+    compiled_patterns: OnceCell<RegexSet>,
+}
+
+impl SecurityRules {
+    fn compiled(&self) -> &RegexSet {
+        self.compiled_patterns.get_or_init(|| {
+            RegexSet::new(&self.forbidden_patterns).unwrap_or_else(|_| RegexSet::empty())
+        })
+    }
+
+    /// Names of checks that are effectively off because their backing
+    /// list is empty, for surfacing on the security dashboard — an
+    /// empty `forbidden_patterns` is easy to miss in config review.
+    pub fn disabled_checks(&self) -> Vec<&'static str> {
+        let mut disabled = Vec::new();
+        if self.forbidden_paths.is_empty() {
+            disabled.push("forbidden_paths");
+        }
+        if self.forbidden_patterns.is_empty() {
+            disabled.push("forbidden_patterns");
+        }
+        disabled
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleViolation {
+    pub rule: String,
+    pub detail: String,
+}
+
+/// Evaluates `rules` against `request`, returning every violation found
+/// (empty means the request passes). Callers should refuse to route a
+/// request into approval if this returns anything.
+pub fn evaluate(rules: &SecurityRules, request: &ModificationRequest) -> Vec<RuleViolation> {
+    let mut violations = Vec::new();
+    let path_str = request.file_path.to_string_lossy();
+
+    for forbidden in &rules.forbidden_paths {
+        if path_str.contains(forbidden.as_str()) {
+            violations.push(RuleViolation {
+                rule: "forbidden_paths".to_string(),
+                detail: format!("{path_str} matches forbidden path {forbidden}"),
+            });
+        }
+    }
+
+    let added_lines: Vec<&str> = request
+        .diff
+        .lines()
+        .filter(|line| line.starts_with('+') && !line.starts_with("+++"))
+        .collect();
+
+    let pattern_set = rules.compiled();
+    for line in &added_lines {
+        for index in pattern_set.matches(line).into_iter() {
+            violations.push(RuleViolation {
+                rule: "forbidden_patterns".to_string(),
+                detail: format!(
+                    "added line matches forbidden pattern `{}`",
+                    rules.forbidden_patterns[index]
+                ),
+            });
+        }
+    }
+
+    let changed_lines = request
+        .diff
+        .lines()
+        .filter(|line| {
+            (line.starts_with('+') && !line.starts_with("+++"))
+                || (line.starts_with('-') && !line.starts_with("---"))
+        })
+        .count();
+
+    if changed_lines > rules.max_changed_lines {
+        violations.push(RuleViolation {
+            rule: "max_changed_lines".to_string(),
+            detail: format!("{changed_lines} lines changed, limit is {}", rules.max_changed_lines),
+        });
+    }
+
+    violations
+}
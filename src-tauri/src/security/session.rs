@@ -0,0 +1,89 @@
+//! Fingerprint-bound admin session tokens that expire after
+//! `SESSION_TTL`. `issue_session_command` issues one without an
+//! admin-code check, and the privileged commands still take the admin
+//! code rather than a token.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rand::RngCore;
+
+const SESSION_TTL: Duration = Duration::from_secs(30 * 60);
+
+pub struct AdminSession {
+    pub issued_at: Instant,
+    pub expires_at: Instant,
+    /// Hash of the machine + OS user the token was issued to. A token
+    /// presented from anywhere else is rejected even if it's otherwise
+    /// unexpired.
+    pub fingerprint: String,
+}
+
+/// In-memory admin session store. Tokens are opaque random strings, not
+/// JWTs: there's nothing for a client to decode and no need to validate
+/// a signature across process restarts, since sessions don't need to
+/// survive one.
+pub struct SessionStore {
+    sessions: Mutex<HashMap<String, AdminSession>>,
+}
+
+impl SessionStore {
+    pub fn new() -> Self {
+        Self { sessions: Mutex::new(HashMap::new()) }
+    }
+
+    /// Issues a new 256-bit session token valid for `SESSION_TTL`,
+    /// bound to `fingerprint` (the issuing machine + OS user).
+    pub fn issue(&self, fingerprint: &str) -> String {
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let token = hex::encode(bytes);
+
+        let now = Instant::now();
+        self.sessions.lock().unwrap().insert(
+            token.clone(),
+            AdminSession { issued_at: now, expires_at: now + SESSION_TTL, fingerprint: fingerprint.to_string() },
+        );
+        token
+    }
+
+    /// Returns whether `token` refers to a live, unexpired session
+    /// issued to `fingerprint`. A token presented with the wrong
+    /// fingerprint is rejected even if it hasn't expired — most likely
+    /// it was copied off the issuing machine. Expired sessions are
+    /// evicted as a side effect. Looks up the token with a
+    /// constant-time comparison against every stored key rather than a
+    /// hash-map `get`, so a client guessing tokens can't learn anything
+    /// from how long the check takes.
+    pub fn is_valid(&self, token: &str, fingerprint: &str) -> bool {
+        let mut sessions = self.sessions.lock().unwrap();
+        let matched = sessions
+            .keys()
+            .find(|stored| crate::security::constant_time::eq(stored, token))
+            .cloned();
+
+        match matched {
+            Some(key) => {
+                let session = &sessions[&key];
+                let still_live = session.expires_at > Instant::now();
+                let fingerprint_matches = crate::security::constant_time::eq(&session.fingerprint, fingerprint);
+                if !still_live {
+                    sessions.remove(&key);
+                }
+                still_live && fingerprint_matches
+            }
+            None => false,
+        }
+    }
+
+    pub fn revoke(&self, token: &str) {
+        self.sessions.lock().unwrap().remove(token);
+    }
+}
+
+impl Default for SessionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
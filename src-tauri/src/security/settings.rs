@@ -0,0 +1,78 @@
+//! Global switches for the self-modification workflow: whether it's
+//! enabled at all, and which kinds of files it's allowed to touch.
+//! Previously part of config but never actually consulted anywhere.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::security::biometric::CriticalAction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModificationType {
+    Code,
+    Config,
+    Documentation,
+    Data,
+}
+
+/// Classifies a file path by extension. Unrecognized extensions (and
+/// extensionless files) fall back to `Data` as the most restrictive
+/// reasonable default rather than guessing.
+pub fn classify(path: &Path) -> ModificationType {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("rs" | "ts" | "tsx" | "js" | "jsx" | "py") => ModificationType::Code,
+        Some("toml" | "json" | "yaml" | "yml") => ModificationType::Config,
+        Some("md" | "mdx") => ModificationType::Documentation,
+        _ => ModificationType::Data,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecuritySettings {
+    pub code_modification_enabled: bool,
+    pub allowed_modification_types: Vec<ModificationType>,
+    /// Critical actions that additionally require OS-native biometric
+    /// confirmation, on top of whatever other checks already gate them.
+    #[serde(default)]
+    pub require_biometric_for: Vec<CriticalAction>,
+    /// Endpoints notified (via `notifications::WebhookNotifier`) when a
+    /// modification needs approval or reaches quorum. The
+    /// signing secret lives in the keyring (`webhook_signing_secret`),
+    /// not here, same as the admin code hash.
+    #[serde(default)]
+    pub webhook_urls: Vec<String>,
+}
+
+impl Default for SecuritySettings {
+    fn default() -> Self {
+        Self {
+            code_modification_enabled: true,
+            allowed_modification_types: vec![ModificationType::Config, ModificationType::Documentation],
+            require_biometric_for: Vec::new(),
+            webhook_urls: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ModificationTypeError {
+    #[error("code modification is disabled")]
+    Disabled,
+    #[error("modification type {0:?} is not in the allowed list")]
+    NotAllowed(ModificationType),
+}
+
+impl SecuritySettings {
+    pub fn check(&self, path: &Path) -> Result<ModificationType, ModificationTypeError> {
+        if !self.code_modification_enabled {
+            return Err(ModificationTypeError::Disabled);
+        }
+        let kind = classify(path);
+        if !self.allowed_modification_types.contains(&kind) {
+            return Err(ModificationTypeError::NotAllowed(kind));
+        }
+        Ok(kind)
+    }
+}
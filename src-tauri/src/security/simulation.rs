@@ -0,0 +1,98 @@
+//! Runs a modification request's diff in an isolated git worktree —
+//! build, tests, and security rules — without ever touching the real
+//! working tree or changing the request's status. Lets an approver see
+//! the consequences before committing to anything.
+
+use std::path::Path;
+use std::process::Command;
+
+use chrono::{DateTime, Utc};
+use git2::Repository;
+use serde::{Deserialize, Serialize};
+
+use crate::security::modifications::ModificationRequest;
+use crate::security::rules::{RuleViolation, SecurityRules};
+use crate::security::static_analysis::StaticFinding;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub ran_at: DateTime<Utc>,
+    pub build_passed: bool,
+    pub test_passed: bool,
+    pub rule_violations: Vec<RuleViolation>,
+    pub static_findings: Vec<StaticFinding>,
+    /// Tail of stderr from a failing build or test run, truncated to
+    /// the last 20 lines so it's useful without flooding the UI.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SimulationError {
+    #[error(transparent)]
+    Git(#[from] git2::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("failed to apply diff: {0}")]
+    Diff(String),
+}
+
+pub fn simulate(
+    repo_path: &Path,
+    request: &ModificationRequest,
+    rules: &SecurityRules,
+) -> Result<SimulationResult, SimulationError> {
+    let repo = Repository::open(repo_path)?;
+
+    let worktree_dir = std::env::temp_dir().join(format!("dyht-simulation-{}", request.id));
+    let _ = std::fs::remove_dir_all(&worktree_dir);
+    let worktree = repo.worktree(&format!("simulation-{}", request.id), &worktree_dir, None)?;
+    Repository::open_from_worktree(&worktree)?;
+
+    let relative_path = request.file_path.strip_prefix(repo_path).unwrap_or(&request.file_path);
+    let target_in_worktree = worktree_dir.join(relative_path);
+    let original = std::fs::read_to_string(&target_in_worktree)?;
+    let patch = diffy::Patch::from_str(&request.diff).map_err(|e| SimulationError::Diff(e.to_string()))?;
+    let patched = diffy::apply(&original, &patch).map_err(|e| SimulationError::Diff(e.to_string()))?;
+    std::fs::write(&target_in_worktree, &patched)?;
+
+    let mut warnings = Vec::new();
+
+    let build_output = Command::new("cargo").arg("build").current_dir(&worktree_dir).output()?;
+    let build_passed = build_output.status.success();
+    if !build_passed {
+        warnings.push(tail_of(&build_output.stderr));
+    }
+
+    let test_passed = if build_passed {
+        let test_output = Command::new("cargo").arg("test").current_dir(&worktree_dir).output()?;
+        if !test_output.status.success() {
+            warnings.push(tail_of(&test_output.stderr));
+        }
+        test_output.status.success()
+    } else {
+        false
+    };
+
+    let rule_violations = crate::security::rules::evaluate(rules, request);
+    let static_findings =
+        crate::security::static_analysis::analyze_source(&patched).unwrap_or_default();
+
+    let _ = worktree.prune(None);
+    let _ = std::fs::remove_dir_all(&worktree_dir);
+
+    Ok(SimulationResult {
+        ran_at: Utc::now(),
+        build_passed,
+        test_passed,
+        rule_violations,
+        static_findings,
+        warnings,
+    })
+}
+
+fn tail_of(bytes: &[u8]) -> String {
+    let text = String::from_utf8_lossy(bytes);
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines.len().saturating_sub(20);
+    lines[start..].join("\n")
+}
@@ -0,0 +1,184 @@
+//! Parses a proposed Rust change with `syn` and flags concrete
+//! dangerous constructs, as a complement to the regex-based
+//! `SecurityRules` patterns — regexes catch known bad strings, this
+//! catches the actual shape of the code regardless of formatting.
+
+use serde::{Deserialize, Serialize};
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    UnsafeBlock,
+    ShellCommand,
+    FileDeletion,
+    NetworkCall,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaticFinding {
+    pub kind: FindingKind,
+    pub description: String,
+    pub line: usize,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnalysisError {
+    #[error("failed to parse proposed source: {0}")]
+    Parse(#[from] syn::Error),
+    #[error("failed to apply diff: {0}")]
+    Diff(String),
+}
+
+/// Applies `diff` to `original` in memory (no filesystem writes) and
+/// runs the static analyzer over the result.
+pub fn analyze_diff(original: &str, diff: &str) -> Result<Vec<StaticFinding>, AnalysisError> {
+    let patch = diffy::Patch::from_str(diff).map_err(|e| AnalysisError::Diff(e.to_string()))?;
+    let proposed = diffy::apply(original, &patch).map_err(|e| AnalysisError::Diff(e.to_string()))?;
+    analyze_source(&proposed)
+}
+
+pub fn analyze_source(source: &str) -> Result<Vec<StaticFinding>, AnalysisError> {
+    let file = syn::parse_file(source)?;
+    let mut visitor = FindingVisitor { findings: Vec::new() };
+    visitor.visit_file(&file);
+    Ok(visitor.findings)
+}
+
+struct FindingVisitor {
+    findings: Vec<StaticFinding>,
+}
+
+const SHELL_PATHS: &[&str] = &["Command"];
+const FILE_DELETION_PATHS: &[&str] = &["remove_file", "remove_dir", "remove_dir_all"];
+const NETWORK_PATHS: &[&str] = &["TcpStream", "TcpListener", "reqwest", "hyper", "UdpSocket"];
+
+impl FindingVisitor {
+    fn push(&mut self, kind: FindingKind, description: impl Into<String>, line: usize) {
+        self.findings.push(StaticFinding { kind, description: description.into(), line });
+    }
+
+    fn path_last_segment(path: &syn::Path) -> Option<String> {
+        path.segments.last().map(|seg| seg.ident.to_string())
+    }
+
+    fn path_contains(path: &syn::Path, needle: &str) -> bool {
+        path.segments.iter().any(|seg| seg.ident == needle)
+    }
+}
+
+impl<'ast> Visit<'ast> for FindingVisitor {
+    fn visit_expr_unsafe(&mut self, node: &'ast syn::ExprUnsafe) {
+        self.push(FindingKind::UnsafeBlock, "unsafe block", node.span().start().line);
+        visit::visit_expr_unsafe(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(expr_path) = node.func.as_ref() {
+            let path = &expr_path.path;
+            if let Some(last) = Self::path_last_segment(path) {
+                if FILE_DELETION_PATHS.contains(&last.as_str()) {
+                    self.push(
+                        FindingKind::FileDeletion,
+                        format!("call to {last}"),
+                        node.span().start().line,
+                    );
+                }
+            }
+            for needle in NETWORK_PATHS {
+                if Self::path_contains(path, needle) {
+                    self.push(
+                        FindingKind::NetworkCall,
+                        format!("reference to {needle}"),
+                        node.span().start().line,
+                    );
+                    break;
+                }
+            }
+            for needle in SHELL_PATHS {
+                if Self::path_contains(path, needle) {
+                    self.push(
+                        FindingKind::ShellCommand,
+                        format!("reference to {needle}"),
+                        node.span().start().line,
+                    );
+                    break;
+                }
+            }
+        }
+        visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_path(&mut self, node: &'ast syn::ExprPath) {
+        for needle in NETWORK_PATHS {
+            if Self::path_contains(&node.path, needle) {
+                self.push(
+                    FindingKind::NetworkCall,
+                    format!("reference to {needle}"),
+                    node.span().start().line,
+                );
+                break;
+            }
+        }
+        for needle in SHELL_PATHS {
+            if Self::path_contains(&node.path, needle) {
+                self.push(
+                    FindingKind::ShellCommand,
+                    format!("reference to {needle}"),
+                    node.span().start().line,
+                );
+                break;
+            }
+        }
+        visit::visit_expr_path(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_unsafe_block_is_flagged() {
+        let findings = analyze_source("fn f() { unsafe { std::ptr::null::<u8>(); } }").unwrap();
+        assert!(findings.iter().any(|f| f.kind == FindingKind::UnsafeBlock));
+    }
+
+    #[test]
+    fn a_remove_file_call_is_flagged_as_file_deletion() {
+        let findings = analyze_source("fn f() { remove_file(\"x\").ok(); }").unwrap();
+        assert!(findings.iter().any(|f| f.kind == FindingKind::FileDeletion));
+    }
+
+    #[test]
+    fn a_reference_to_command_is_flagged_as_a_shell_command() {
+        let findings = analyze_source("fn f() { Command::new(\"sh\"); }").unwrap();
+        assert!(findings.iter().any(|f| f.kind == FindingKind::ShellCommand));
+    }
+
+    #[test]
+    fn a_reference_to_tcpstream_is_flagged_as_a_network_call() {
+        let findings = analyze_source("fn f() { let _ = TcpStream::connect(\"x\"); }").unwrap();
+        assert!(findings.iter().any(|f| f.kind == FindingKind::NetworkCall));
+    }
+
+    #[test]
+    fn benign_source_produces_no_findings() {
+        let findings = analyze_source("fn add(a: i32, b: i32) -> i32 { a + b }").unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn unparseable_source_is_an_error() {
+        assert!(analyze_source("fn f( {").is_err());
+    }
+
+    #[test]
+    fn analyze_diff_applies_the_patch_before_analyzing() {
+        let original = "fn f() {}\n";
+        let diff = diffy::create_patch(original, "fn f() { unsafe { std::ptr::null::<u8>(); } }\n").to_string();
+        let findings = analyze_diff(original, &diff).unwrap();
+        assert!(findings.iter().any(|f| f.kind == FindingKind::UnsafeBlock));
+    }
+}
@@ -0,0 +1,188 @@
+//! Encrypted-at-rest store for API keys and tokens the agent's tools
+//! need. The vault file is AES-256-GCM encrypted with a key derived
+//! from a master password via Argon2id; nothing is held in plaintext
+//! on disk, and the derived key only lives in memory once unlocked.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::Argon2;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, thiserror::Error)]
+pub enum VaultError {
+    #[error("vault is locked")]
+    Locked,
+    #[error("incorrect master password")]
+    WrongPassword,
+    #[error("secret '{0}' not found")]
+    NotFound(String),
+    #[error("key derivation failed")]
+    Kdf,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct VaultContents {
+    secrets: HashMap<String, String>,
+}
+
+/// An unlocked, in-memory view of the vault. Dropped (and re-locked) by
+/// simply letting it go out of scope — the derived key is not persisted
+/// anywhere.
+pub struct Vault {
+    path: PathBuf,
+    key: [u8; 32],
+    contents: VaultContents,
+}
+
+impl Vault {
+    /// Unlocks the vault at `path` with `master_password`, creating an
+    /// empty vault there if the file doesn't exist yet.
+    pub fn unlock(path: &Path, master_password: &str) -> Result<Self, VaultError> {
+        if !path.exists() {
+            let mut salt = [0u8; SALT_LEN];
+            OsRng.fill_bytes(&mut salt);
+            let key = derive_key(master_password, &salt)?;
+            let vault = Self { path: path.to_path_buf(), key, contents: VaultContents::default() };
+            vault.save_with_salt(&salt)?;
+            return Ok(vault);
+        }
+
+        let raw = std::fs::read(path)?;
+        if raw.len() < SALT_LEN + NONCE_LEN {
+            return Err(VaultError::WrongPassword);
+        }
+        let (salt, rest) = raw.split_at(SALT_LEN);
+        let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+
+        let key = derive_key(master_password, salt)?;
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| VaultError::Kdf)?;
+        let nonce = Nonce::from_slice(nonce_bytes);
+        let plaintext = cipher
+            .decrypt(nonce, ciphertext)
+            .map_err(|_| VaultError::WrongPassword)?;
+
+        let contents: VaultContents = serde_json::from_slice(&plaintext)?;
+        Ok(Self { path: path.to_path_buf(), key, contents })
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), VaultError> {
+        self.contents.secrets.insert(name.to_string(), value.to_string());
+        self.save()
+    }
+
+    pub fn get(&self, name: &str) -> Result<&str, VaultError> {
+        self.contents
+            .secrets
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| VaultError::NotFound(name.to_string()))
+    }
+
+    pub fn list(&self) -> Vec<&str> {
+        self.contents.secrets.keys().map(String::as_str).collect()
+    }
+
+    pub fn delete(&mut self, name: &str) -> Result<(), VaultError> {
+        self.contents
+            .secrets
+            .remove(name)
+            .ok_or_else(|| VaultError::NotFound(name.to_string()))?;
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), VaultError> {
+        let raw = std::fs::read(&self.path)?;
+        let salt = &raw[..SALT_LEN];
+        self.save_with_salt(salt)
+    }
+
+    fn save_with_salt(&self, salt: &[u8]) -> Result<(), VaultError> {
+        let cipher = Aes256Gcm::new_from_slice(&self.key).map_err(|_| VaultError::Kdf)?;
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        let plaintext = serde_json::to_vec(&self.contents)?;
+        let ciphertext = cipher
+            .encrypt(nonce, plaintext.as_ref())
+            .map_err(|_| VaultError::Kdf)?;
+
+        let mut out = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+        out.extend_from_slice(salt);
+        out.extend_from_slice(&nonce_bytes);
+        out.extend_from_slice(&ciphertext);
+        std::fs::write(&self.path, out)?;
+        Ok(())
+    }
+}
+
+fn derive_key(master_password: &str, salt: &[u8]) -> Result<[u8; 32], VaultError> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(master_password.as_bytes(), salt, &mut key)
+        .map_err(|_| VaultError::Kdf)?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_path(test_name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("dyht-vault-test-{test_name}-{}.json", std::process::id()))
+    }
+
+    #[test]
+    fn set_then_get_roundtrips_through_encryption() {
+        let path = scratch_path("roundtrip");
+        let mut vault = Vault::unlock(&path, "correct horse battery staple").unwrap();
+        vault.set("api_key", "sekret").unwrap();
+        assert_eq!(vault.get("api_key").unwrap(), "sekret");
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn unlocking_with_the_wrong_password_fails() {
+        let path = scratch_path("wrong-password");
+        {
+            let mut vault = Vault::unlock(&path, "the-real-password").unwrap();
+            vault.set("api_key", "sekret").unwrap();
+        }
+
+        let result = Vault::unlock(&path, "a-guess");
+        assert!(matches!(result, Err(VaultError::WrongPassword)));
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn secrets_persist_across_separate_unlocks() {
+        let path = scratch_path("persist");
+        {
+            let mut vault = Vault::unlock(&path, "hunter2").unwrap();
+            vault.set("api_key", "sekret").unwrap();
+        }
+
+        let vault = Vault::unlock(&path, "hunter2").unwrap();
+        assert_eq!(vault.get("api_key").unwrap(), "sekret");
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn deleting_an_unknown_secret_fails() {
+        let path = scratch_path("delete-unknown");
+        let mut vault = Vault::unlock(&path, "hunter2").unwrap();
+        assert!(matches!(vault.delete("does-not-exist"), Err(VaultError::NotFound(_))));
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,21 @@
+//! A process-lifetime session id, minted by `AppSession::start`, so
+//! audit entries and security events written during one run can be tied
+//! back to the same app session without each call site minting its own.
+//! `security::events::RequestContext::from_session` layers a fresh
+//! request id on top, narrowing correlation down to "everything logged
+//! while handling this one call". `run` doesn't start an `AppSession`;
+//! the Tauri commands build their `RequestContext` from the
+//! caller-supplied session id instead.
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy)]
+pub struct AppSession {
+    pub session_id: Uuid,
+}
+
+impl AppSession {
+    pub fn start() -> Self {
+        Self { session_id: Uuid::new_v4() }
+    }
+}
@@ -0,0 +1,34 @@
+//! Runtime-tunable agent settings. Grows as individual subsystems need a
+//! place to hang their configurable knobs; persistence, layered
+//! overrides, and hot-reload live in `config`.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSettings {
+    /// Weight given to the lexical (BM25/tsvector) score in hybrid
+    /// memory search, combined with `vector_search_weight` via
+    /// reciprocal rank fusion.
+    pub lexical_search_weight: f32,
+    /// Weight given to the vector (cosine similarity) score in hybrid
+    /// memory search.
+    pub vector_search_weight: f32,
+    /// Minimum fused score a memory must reach to be included in RAG
+    /// context. Filters out results that merely happened to rank above
+    /// nothing in a sparse result set.
+    pub rag_similarity_threshold: f32,
+    /// Maximum number of memories returned as RAG context per query.
+    pub rag_max_results: i64,
+}
+
+impl Default for AgentSettings {
+    fn default() -> Self {
+        Self {
+            lexical_search_weight: 0.4,
+            vector_search_weight: 0.6,
+            rag_similarity_threshold: 0.15,
+            rag_max_results: 8,
+        }
+    }
+}
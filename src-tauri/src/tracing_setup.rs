@@ -0,0 +1,46 @@
+//! Wires up the global `tracing` subscriber with a reloadable filter, so
+//! verbosity can be turned up from the frontend while chasing an
+//! incident instead of requiring a restart with `RUST_LOG` set.
+
+use std::str::FromStr;
+
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
+
+pub type ReloadHandle = reload::Handle<EnvFilter, Registry>;
+
+const DEFAULT_FILTER: &str = "info";
+
+/// Installs the global subscriber. Must be called once, at startup,
+/// before the first `tracing::info!`/span is recorded; the returned
+/// handle is registered as Tauri state so `set_log_level_command` can
+/// reach it later.
+pub fn init() -> ReloadHandle {
+    init_with(None)
+}
+
+/// Same as `init`, but `directive` (e.g. from the `--log-level` CLI
+/// flag) takes priority over `RUST_LOG` and the built-in default, for a
+/// scripted run that wants a fixed verbosity regardless of environment.
+pub fn init_with(directive: Option<&str>) -> ReloadHandle {
+    let filter = directive
+        .map(EnvFilter::new)
+        .or_else(|| EnvFilter::try_from_default_env().ok())
+        .unwrap_or_else(|| EnvFilter::new(DEFAULT_FILTER));
+    let (filter, handle) = reload::Layer::new(filter);
+    tracing_subscriber::registry().with(filter).with(fmt::layer()).init();
+    handle
+}
+
+/// Swaps in a new filter directive (e.g. `"debug"`, `"dyht=trace,info"`)
+/// without restarting the app.
+pub fn set_level(handle: &ReloadHandle, directive: &str) -> Result<(), String> {
+    let filter = EnvFilter::from_str(directive).map_err(|e| e.to_string())?;
+    handle.reload(filter).map_err(|e| e.to_string())
+}
+
+/// Renders the active filter back to its directive string, so the
+/// frontend can show what's currently in effect.
+pub fn current_level(handle: &ReloadHandle) -> String {
+    handle.with_current(|filter| filter.to_string()).unwrap_or_default()
+}